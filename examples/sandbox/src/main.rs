@@ -1,83 +1,55 @@
-use std::fmt::Display;
-use std::sync::mpsc::{self, TryRecvError};
 use std::thread;
+use std::time::Duration;
 
 use eframe::egui;
 
-use egui_modal_spinner::ModalSpinner;
-
-#[derive(PartialEq)]
-enum ThreadState {
-    LoadingA,
-    LoadingB,
-    LoadingC,
-    Finished,
-}
-
-impl Display for ThreadState {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Self::LoadingA => write!(f, "Loading dogs 🐕 ..."),
-            Self::LoadingB => write!(f, "Loading cats 🐈 ..."),
-            Self::LoadingC => write!(f, "Loading penguins 🐧 ..."),
-            Self::Finished => write!(f, "Finished"),
-        }
-    }
-}
+use egui_modal_spinner::{ModalSpinner, SpinnerTask};
 
 struct MyApp {
     spinner: ModalSpinner,
-    result_recv: Option<mpsc::Receiver<ThreadState>>,
-    thread_state: Option<ThreadState>,
+    task: Option<SpinnerTask<()>>,
 }
 
 impl MyApp {
     pub fn new() -> Self {
         Self {
-            spinner: ModalSpinner::new(),
-            result_recv: None,
-            thread_state: None,
+            spinner: ModalSpinner::new()
+                .cancellable(true)
+                .fade_in_duration(Duration::from_millis(100))
+                .fade_out_duration(Duration::from_millis(100))
+                .stages(["Loading dogs 🐕", "Loading cats 🐈", "Loading penguins 🐧"]),
+            task: None,
         }
     }
 
-    fn exec_task(&mut self) {
-        let (tx, rx) = mpsc::channel();
-        self.result_recv = Some(rx);
-        self.thread_state = None;
+    fn exec_task(&mut self, ctx: &egui::Context) {
+        let token = self.spinner.cancellation_token();
+        let progress = self.spinner.progress_handle(ctx);
 
-        thread::spawn(move || {
-            let _ = tx.send(ThreadState::LoadingA);
-            thread::sleep(std::time::Duration::from_secs(2));
+        self.task = Some(self.spinner.run(ctx, move || {
+            let durations = [2, 1, 2];
 
-            let _ = tx.send(ThreadState::LoadingB);
-            thread::sleep(std::time::Duration::from_secs(1));
+            for (i, secs) in durations.iter().enumerate() {
+                if token.is_cancelled() {
+                    return;
+                }
 
-            let _ = tx.send(ThreadState::LoadingC);
-            thread::sleep(std::time::Duration::from_secs(2));
+                thread::sleep(Duration::from_secs(*secs));
 
-            let _ = tx.send(ThreadState::Finished);
-        });
+                progress.advance_stage();
+                progress.set_progress((i + 1) as f32 / durations.len() as f32);
+            }
+        }));
     }
 
     fn update_task_thread(&mut self) {
-        if let Some(rx) = &self.result_recv {
-            match rx.try_recv() {
-                Ok(state) => {
-                    if state == ThreadState::Finished {
-                        self.spinner.close();
-                        self.result_recv = None;
-                        self.thread_state = None;
-                    }
-
-                    self.thread_state = Some(state);
-                }
-                Err(err) => {
-                    if err == TryRecvError::Disconnected {
-                        self.spinner.close();
-                        self.result_recv = None;
-                        println!("thread ended unexpectedly");
-                    }
+        if let Some(task) = &mut self.task {
+            if let Some(result) = task.take_result() {
+                if let Err(err) = result {
+                    println!("{err}");
                 }
+
+                self.task = None;
             }
         }
     }
@@ -85,26 +57,17 @@ impl MyApp {
 
 impl eframe::App for MyApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
-        ctx.style_mut(|s| s.animation_time = 0.1);
-
         egui::CentralPanel::default().show(ctx, |ui| {
             ui.heading("My egui application");
             egui::widgets::global_theme_preference_buttons(ui);
 
             if ui.button("Do something resource heavy!").clicked() {
-                self.exec_task();
-
-                self.spinner.open();
+                self.exec_task(ctx);
             }
 
             self.update_task_thread();
 
-            self.spinner.update_with_content(ctx, |ui| {
-                if let Some(s) = &self.thread_state {
-                    ui.add_space(ui.spacing().item_spacing.y);
-                    ui.label(s.to_string());
-                }
-            });
+            self.spinner.update(ctx);
         });
     }
 }
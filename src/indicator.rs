@@ -0,0 +1,616 @@
+use std::f64::consts::TAU;
+use std::fmt::Debug;
+use std::time::Duration;
+
+/// Trait for the indicator displayed by the `ModalSpinner`.
+///
+/// Implement this trait to display a custom indicator that matches the branding of your
+/// application. The indicator can be set using `ModalSpinner::indicator`.
+pub trait SpinnerIndicator: Debug + Send + Sync {
+    /// Paints the indicator into the given rect.
+    ///
+    /// * `painter` - The painter used to paint the indicator. The painter itself has no opacity
+    ///   applied, the indicator is responsible for applying the given `opacity`.
+    /// * `rect` - The rect the indicator should be painted into.
+    /// * `color` - The color configured using `ModalSpinner::spinner_color`
+    ///   or the strong text color of the current style.
+    /// * `time` - The time in seconds used to animate the indicator.
+    /// * `opacity` - The opacity of the modal in the range `0.0..=1.0`. Is below `1.0` while
+    ///   the modal is fading in or out.
+    fn paint(
+        &self,
+        painter: &egui::Painter,
+        rect: egui::Rect,
+        color: egui::Color32,
+        time: f64,
+        opacity: f32,
+    );
+
+    /// Gets the time after which the indicator needs to be painted again.
+    ///
+    /// * `time` - The time in seconds the indicator was last painted at.
+    ///
+    /// The default requests a repaint in every frame, which is required for
+    /// continuously animated indicators.
+    fn repaint_after(&self, time: f64) -> Duration {
+        let _ = time;
+        Duration::ZERO
+    }
+}
+
+/// The default indicator that paints a rotating arc, the same as `egui::Spinner`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArcIndicator {
+    stroke_width: f32,
+}
+
+impl Default for ArcIndicator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ArcIndicator {
+    /// Creates a new arc indicator.
+    pub const fn new() -> Self {
+        Self { stroke_width: 3.0 }
+    }
+
+    /// Sets the stroke width of the arc.
+    pub const fn stroke_width(mut self, stroke_width: f32) -> Self {
+        self.stroke_width = stroke_width;
+        self
+    }
+}
+
+impl SpinnerIndicator for ArcIndicator {
+    fn paint(
+        &self,
+        painter: &egui::Painter,
+        rect: egui::Rect,
+        color: egui::Color32,
+        time: f64,
+        opacity: f32,
+    ) {
+        const N_POINTS: u8 = 20;
+
+        let radius = rect.height() / 2.0 - 2.0;
+        let start_angle = time * TAU;
+        let end_angle = 240f64.to_radians().mul_add(time.sin(), start_angle);
+
+        let points: Vec<egui::Pos2> = (0..N_POINTS)
+            .map(|i| {
+                let angle = egui::lerp(start_angle..=end_angle, f64::from(i) / f64::from(N_POINTS));
+                #[allow(clippy::cast_possible_truncation)]
+                let (sin, cos) = (angle.sin() as f32, angle.cos() as f32);
+                rect.center() + radius * egui::vec2(cos, sin)
+            })
+            .collect();
+
+        painter.add(egui::Shape::line(
+            points,
+            egui::Stroke::new(self.stroke_width, color.gamma_multiply(opacity)),
+        ));
+    }
+}
+
+/// Indicator that paints a row of pulsing dots.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DotsIndicator {
+    count: u8,
+}
+
+impl Default for DotsIndicator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DotsIndicator {
+    /// Creates a new dots indicator with three dots.
+    pub const fn new() -> Self {
+        Self { count: 3 }
+    }
+
+    /// Sets the number of dots.
+    pub const fn count(mut self, count: u8) -> Self {
+        self.count = count;
+        self
+    }
+}
+
+impl SpinnerIndicator for DotsIndicator {
+    fn paint(
+        &self,
+        painter: &egui::Painter,
+        rect: egui::Rect,
+        color: egui::Color32,
+        time: f64,
+        opacity: f32,
+    ) {
+        if self.count == 0 {
+            return;
+        }
+
+        let count = f32::from(self.count);
+        let spacing = rect.width() / count;
+        let max_radius = (spacing / 2.0 - 1.0).min(rect.height() / 2.0);
+
+        for i in 0..self.count {
+            let offset = f64::from(i) / f64::from(self.count);
+            let phase = (time - offset) * TAU;
+            #[allow(clippy::cast_possible_truncation)]
+            let pulse = (phase.sin() as f32).mul_add(0.5, 0.5);
+
+            let center = egui::pos2(
+                (f32::from(i) + 0.5).mul_add(spacing, rect.left()),
+                rect.center().y,
+            );
+
+            painter.circle_filled(
+                center,
+                max_radius * pulse.mul_add(0.6, 0.4),
+                color.gamma_multiply(opacity * pulse.mul_add(0.7, 0.3)),
+            );
+        }
+    }
+}
+
+/// Indicator that paints a bar bouncing back and forth inside a track.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BarIndicator {
+    bar_width: f32,
+}
+
+impl Default for BarIndicator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BarIndicator {
+    /// Creates a new bar indicator.
+    pub const fn new() -> Self {
+        Self { bar_width: 0.3 }
+    }
+
+    /// Sets the width of the bar relative to the width of the track.
+    /// The value is clamped to the range `0.0..=1.0`.
+    pub fn bar_width(mut self, bar_width: f32) -> Self {
+        self.bar_width = bar_width.clamp(0.0, 1.0);
+        self
+    }
+}
+
+impl SpinnerIndicator for BarIndicator {
+    fn paint(
+        &self,
+        painter: &egui::Painter,
+        rect: egui::Rect,
+        color: egui::Color32,
+        time: f64,
+        opacity: f32,
+    ) {
+        let track = egui::Rect::from_center_size(
+            rect.center(),
+            egui::vec2(rect.width(), rect.height() / 4.0),
+        );
+        let rounding = track.height() / 2.0;
+
+        painter.rect_filled(track, rounding, color.gamma_multiply(opacity * 0.2));
+
+        #[allow(clippy::cast_possible_truncation)]
+        let position = ((time * TAU / 2.0).sin() as f32).mul_add(0.5, 0.5);
+
+        let bar_width = track.width() * self.bar_width;
+        let left = (track.width() - bar_width).mul_add(position, track.left());
+
+        let bar = egui::Rect::from_min_size(
+            egui::pos2(left, track.top()),
+            egui::vec2(bar_width, track.height()),
+        );
+
+        painter.rect_filled(bar, rounding, color.gamma_multiply(opacity));
+    }
+}
+
+/// Indicator that paints a rotating ring of segments.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RingIndicator {
+    segments: u8,
+}
+
+impl Default for RingIndicator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RingIndicator {
+    /// Creates a new ring indicator with eight segments.
+    pub const fn new() -> Self {
+        Self { segments: 8 }
+    }
+
+    /// Sets the number of segments.
+    pub const fn segments(mut self, segments: u8) -> Self {
+        self.segments = segments;
+        self
+    }
+}
+
+impl SpinnerIndicator for RingIndicator {
+    fn paint(
+        &self,
+        painter: &egui::Painter,
+        rect: egui::Rect,
+        color: egui::Color32,
+        time: f64,
+        opacity: f32,
+    ) {
+        if self.segments == 0 {
+            return;
+        }
+
+        let segments = f64::from(self.segments);
+        let outer_radius = rect.height() / 2.0 - 1.0;
+        let inner_radius = outer_radius / 2.0;
+        let stroke_width = (outer_radius / 5.0).max(1.0);
+
+        // Index of the segment that is currently highlighted
+        let head = (time * segments).rem_euclid(segments);
+
+        for i in 0..self.segments {
+            let i = f64::from(i);
+            let (sin, cos) = (i * TAU / segments).sin_cos();
+            #[allow(clippy::cast_possible_truncation)]
+            let direction = egui::vec2(cos as f32, sin as f32);
+
+            // Segments fade out the further they are behind the head
+            #[allow(clippy::cast_possible_truncation)]
+            let alpha = (1.0 - (head - i).rem_euclid(segments) / segments) as f32;
+
+            painter.line_segment(
+                [
+                    rect.center() + direction * inner_radius,
+                    rect.center() + direction * outer_radius,
+                ],
+                egui::Stroke::new(stroke_width, color.gamma_multiply(opacity * alpha)),
+            );
+        }
+    }
+}
+
+/// Indicator that plays an animation from a sprite sheet.
+///
+/// The sprite sheet is divided into a grid of frames of the same size which are played
+/// row by row from the top left to the bottom right.
+#[derive(Clone, PartialEq)]
+pub struct SpriteIndicator {
+    texture: egui::TextureHandle,
+    columns: u32,
+    rows: u32,
+    frame_count: u32,
+    fps: f32,
+}
+
+impl Debug for SpriteIndicator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SpriteIndicator")
+            .field("texture", &self.texture.id())
+            .field("columns", &self.columns)
+            .field("rows", &self.rows)
+            .field("frame_count", &self.frame_count)
+            .field("fps", &self.fps)
+            .finish()
+    }
+}
+
+impl SpriteIndicator {
+    /// Creates a new sprite indicator from the given sprite sheet texture.
+    ///
+    /// * `columns` - The number of frames in each row of the sprite sheet.
+    /// * `rows` - The number of rows of the sprite sheet.
+    ///
+    /// By default, all frames of the grid are played with 24 frames per second.
+    pub fn new(texture: egui::TextureHandle, columns: u32, rows: u32) -> Self {
+        let columns = columns.max(1);
+        let rows = rows.max(1);
+
+        Self {
+            texture,
+            columns,
+            rows,
+            frame_count: columns * rows,
+            fps: 24.0,
+        }
+    }
+
+    /// Sets the number of frames of the animation.
+    /// Useful if the last row of the sprite sheet is not completely filled.
+    pub fn frame_count(mut self, frame_count: u32) -> Self {
+        self.frame_count = frame_count.clamp(1, self.columns * self.rows);
+        self
+    }
+
+    /// Sets the number of frames played per second.
+    pub const fn fps(mut self, fps: f32) -> Self {
+        self.fps = fps;
+        self
+    }
+
+    /// Gets the index of the frame displayed at the given time.
+    fn frame_at(&self, time: f64) -> u32 {
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let frame = (time * f64::from(self.fps)).floor().max(0.0) as u64;
+
+        #[allow(clippy::cast_possible_truncation)]
+        let frame = (frame % u64::from(self.frame_count)) as u32;
+
+        frame
+    }
+}
+
+impl SpinnerIndicator for SpriteIndicator {
+    fn paint(
+        &self,
+        painter: &egui::Painter,
+        rect: egui::Rect,
+        _color: egui::Color32,
+        time: f64,
+        opacity: f32,
+    ) {
+        #[allow(clippy::cast_precision_loss)]
+        let (columns, rows) = (self.columns as f32, self.rows as f32);
+
+        let frame = self.frame_at(time);
+
+        #[allow(clippy::cast_precision_loss)]
+        let (column, row) = ((frame % self.columns) as f32, (frame / self.columns) as f32);
+
+        let uv = egui::Rect::from_min_size(
+            egui::pos2(column / columns, row / rows),
+            egui::vec2(1.0 / columns, 1.0 / rows),
+        );
+
+        // Fit the frame into the rect while keeping its aspect ratio
+        let frame_size = self.texture.size_vec2() / egui::vec2(columns, rows);
+        let scale = (rect.width() / frame_size.x).min(rect.height() / frame_size.y);
+        let image_rect = egui::Rect::from_center_size(rect.center(), frame_size * scale);
+
+        painter.image(
+            self.texture.id(),
+            image_rect,
+            uv,
+            egui::Color32::WHITE.gamma_multiply(opacity),
+        );
+    }
+
+    fn repaint_after(&self, time: f64) -> Duration {
+        if self.fps <= 0.0 {
+            return Duration::MAX;
+        }
+
+        let fps = f64::from(self.fps);
+        let next_frame = ((time * fps).floor() + 1.0) / fps;
+
+        Duration::from_secs_f64((next_frame - time).max(0.0))
+    }
+}
+
+/// Indicator that cycles through a list of text frames, for example braille or ASCII spinners.
+///
+/// Useful for minimal or monospace styled applications.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextIndicator {
+    frames: Vec<String>,
+    interval: Duration,
+    font: Option<egui::FontId>,
+}
+
+impl TextIndicator {
+    /// Creates a new text indicator from the given frames.
+    /// Each frame is displayed for the given interval.
+    pub fn new(frames: impl IntoIterator<Item = impl Into<String>>, interval: Duration) -> Self {
+        Self {
+            frames: frames.into_iter().map(Into::into).collect(),
+            interval,
+            font: None,
+        }
+    }
+
+    /// Creates a new text indicator displaying a rotating braille pattern.
+    ///
+    /// Note that the font used must contain the braille glyphs.
+    pub fn braille() -> Self {
+        Self::new(
+            ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"],
+            Duration::from_millis(80),
+        )
+    }
+
+    /// Creates a new text indicator displaying a rotating ASCII line.
+    pub fn ascii() -> Self {
+        Self::new(["|", "/", "-", "\\"], Duration::from_millis(100))
+    }
+
+    /// Creates a new text indicator displaying a growing row of dots.
+    pub fn dots() -> Self {
+        Self::new(["   ", ".  ", ".. ", "..."], Duration::from_millis(300))
+    }
+
+    /// Sets the time each frame is displayed.
+    pub const fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Sets the font used to display the frames.
+    /// By default, a monospace font with the size of the spinner is used.
+    pub fn font(mut self, font: egui::FontId) -> Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Gets the index of the frame displayed at the given time.
+    fn frame_at(&self, time: f64) -> usize {
+        let interval = self.interval.as_secs_f64();
+
+        if interval <= 0.0 || self.frames.is_empty() {
+            return 0;
+        }
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let frame = (time / interval).floor().max(0.0) as usize;
+
+        frame % self.frames.len()
+    }
+}
+
+impl SpinnerIndicator for TextIndicator {
+    fn paint(
+        &self,
+        painter: &egui::Painter,
+        rect: egui::Rect,
+        color: egui::Color32,
+        time: f64,
+        opacity: f32,
+    ) {
+        let Some(frame) = self.frames.get(self.frame_at(time)) else {
+            return;
+        };
+
+        let font = self
+            .font
+            .clone()
+            .unwrap_or_else(|| egui::FontId::monospace(rect.height()));
+
+        painter.text(
+            rect.center(),
+            egui::Align2::CENTER_CENTER,
+            frame,
+            font,
+            color.gamma_multiply(opacity),
+        );
+    }
+
+    fn repaint_after(&self, time: f64) -> Duration {
+        let interval = self.interval.as_secs_f64();
+
+        if interval <= 0.0 || self.frames.len() < 2 {
+            return Duration::MAX;
+        }
+
+        let next_frame = ((time / interval).floor() + 1.0) * interval;
+
+        Duration::from_secs_f64((next_frame - time).max(0.0))
+    }
+}
+
+/// Indicator that paints a dot slowly pulsing in opacity.
+///
+/// Used instead of the configured indicator if reduced motion is enabled.
+/// See `ModalSpinner::reduced_motion`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PulseIndicator {
+    period: Duration,
+}
+
+impl Default for PulseIndicator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PulseIndicator {
+    /// Creates a new pulse indicator.
+    pub const fn new() -> Self {
+        Self {
+            period: Duration::from_secs(2),
+        }
+    }
+
+    /// Sets the duration of a single pulse.
+    /// If zero, the dot is displayed statically.
+    pub const fn period(mut self, period: Duration) -> Self {
+        self.period = period;
+        self
+    }
+
+    /// Gets the opacity of the dot at the given time.
+    fn opacity_at(&self, time: f64) -> f32 {
+        let period = self.period.as_secs_f64();
+
+        if period <= 0.0 {
+            return 1.0;
+        }
+
+        let phase = (time / period * TAU).cos().mul_add(0.5, 0.5);
+
+        #[allow(clippy::cast_possible_truncation)]
+        let phase = phase as f32;
+
+        egui::lerp(0.4..=1.0, phase)
+    }
+}
+
+impl SpinnerIndicator for PulseIndicator {
+    fn paint(
+        &self,
+        painter: &egui::Painter,
+        rect: egui::Rect,
+        color: egui::Color32,
+        time: f64,
+        opacity: f32,
+    ) {
+        painter.circle_filled(
+            rect.center(),
+            rect.height() / 4.0,
+            color.gamma_multiply(opacity * self.opacity_at(time)),
+        );
+    }
+
+    fn repaint_after(&self, _time: f64) -> Duration {
+        if self.period.is_zero() {
+            return Duration::MAX;
+        }
+
+        // The pulse is slow, so a low frame rate is sufficient
+        self.period / 20
+    }
+}
+
+#[test]
+fn test_sprite_timing() {
+    let ctx = egui::Context::default();
+    let texture = ctx.load_texture(
+        "sprite",
+        egui::ColorImage::new([4, 2], egui::Color32::WHITE),
+        egui::TextureOptions::default(),
+    );
+
+    let sprite = SpriteIndicator::new(texture, 2, 2).frame_count(3).fps(10.0);
+
+    assert_eq!(sprite.frame_at(0.0), 0);
+    assert_eq!(sprite.frame_at(0.25), 2);
+    assert_eq!(sprite.frame_at(0.35), 0);
+    assert_eq!(sprite.repaint_after(0.25), Duration::from_secs_f64(0.05));
+}
+
+#[test]
+fn test_text_frames() {
+    let indicator = TextIndicator::ascii();
+
+    assert_eq!(indicator.frame_at(0.05), 0);
+    assert_eq!(indicator.frame_at(0.25), 2);
+    assert_eq!(indicator.frame_at(0.45), 0);
+}
+
+#[test]
+fn test_pulse_opacity() {
+    let indicator = PulseIndicator::new();
+
+    assert!((indicator.opacity_at(0.0) - 1.0).abs() < 1e-6);
+    assert!((indicator.opacity_at(1.0) - 0.4).abs() < 1e-6);
+    assert!((PulseIndicator::new().period(Duration::ZERO).opacity_at(0.5) - 1.0).abs() < 1e-6);
+}
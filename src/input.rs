@@ -0,0 +1,292 @@
+use std::sync::Arc;
+
+/// ID used to store the input blocks inside the context data.
+const BLOCKS_ID: &str = "_modal_spinner_input_blocks";
+/// ID used to store if the cancel key of a spinner was captured.
+const CAPTURED_KEY_ID: &str = "_modal_spinner_captured_key";
+/// ID used to store if the begin pass callback was already registered.
+const REGISTERED_ID: &str = "_modal_spinner_input_registered";
+
+/// Keyboard suppression requested by a single spinner.
+#[derive(Debug, Clone)]
+struct InputBlock {
+    /// ID of the spinner that requested the suppression.
+    id: egui::Id,
+    /// Layer of the spinner content. Input is not suppressed while a widget on
+    /// this layer has the keyboard focus.
+    layer: egui::LayerId,
+    /// The pass in which the suppression was last requested.
+    pass_nr: u64,
+    /// Keyboard shortcuts that are not suppressed.
+    allowed_shortcuts: Vec<egui::KeyboardShortcut>,
+    /// Key that is captured for the spinner before it is suppressed.
+    capture_key: Option<egui::Key>,
+}
+
+impl InputBlock {
+    /// If the given event is not suppressed by this block.
+    fn allows(&self, event: &egui::Event) -> bool {
+        match event {
+            egui::Event::Key { key, modifiers, .. } => self
+                .allowed_shortcuts
+                .iter()
+                .any(|s| s.logical_key == *key && modifiers.matches_logically(s.modifiers)),
+            _ => !is_keyboard_event(event),
+        }
+    }
+
+    /// If the given event is a press of the key captured by this block.
+    fn captures(&self, event: &egui::Event) -> bool {
+        matches!(event, egui::Event::Key { key, pressed: true, repeat: false, modifiers, .. }
+            if Some(*key) == self.capture_key && modifiers.is_none())
+    }
+
+    /// If the given key is not suppressed by this block.
+    fn allows_key(&self, key: egui::Key) -> bool {
+        self.allowed_shortcuts.iter().any(|s| s.logical_key == key)
+    }
+}
+
+/// All keyboard suppressions requested by the spinners of a context.
+#[derive(Debug, Clone, Default)]
+struct InputBlocks {
+    blocks: Vec<InputBlock>,
+}
+
+/// Suppresses keyboard input in the next pass.
+///
+/// The spinner is usually updated at the end of a frame, after the widgets behind the modal
+/// already processed the input. Therefore, the keyboard events are removed at the beginning
+/// of the next pass by a callback registered with `egui::Context::on_begin_pass`.
+/// Must be called in every pass the keyboard input should be suppressed.
+/// Events of the allowed shortcuts are not suppressed. A press of the capture key is
+/// recorded and can be read using `take_captured_key`.
+/// The input is not suppressed while a widget on the given layer has the keyboard focus, so
+/// that the widgets inside the modal can still be used with the keyboard.
+pub fn block_keyboard(
+    ctx: &egui::Context,
+    id: egui::Id,
+    layer: egui::LayerId,
+    allowed_shortcuts: &[egui::KeyboardShortcut],
+    capture_key: Option<egui::Key>,
+) {
+    register(ctx);
+
+    let pass_nr = ctx.cumulative_pass_nr();
+
+    ctx.data_mut(|d| {
+        let blocks = d.get_temp_mut_or_default::<InputBlocks>(egui::Id::new(BLOCKS_ID));
+        blocks.blocks.retain(|b| b.id != id);
+        blocks.blocks.push(InputBlock {
+            id,
+            layer,
+            pass_nr,
+            allowed_shortcuts: allowed_shortcuts.to_vec(),
+            capture_key,
+        });
+    });
+}
+
+/// Checks if the capture key of the spinner with the given ID was pressed since
+/// the last call and resets the state.
+pub fn take_captured_key(ctx: &egui::Context, id: egui::Id) -> bool {
+    ctx.data_mut(|d| d.remove_temp::<bool>(id.with(CAPTURED_KEY_ID)))
+        .unwrap_or_default()
+}
+
+/// Registers the begin pass callback if it is not yet registered.
+fn register(ctx: &egui::Context) {
+    let registered_id = egui::Id::new(REGISTERED_ID);
+
+    if ctx.data(|d| d.get_temp::<bool>(registered_id).unwrap_or_default()) {
+        return;
+    }
+
+    ctx.data_mut(|d| d.insert_temp(registered_id, true));
+    ctx.on_begin_pass("egui_modal_spinner", Arc::new(filter_input));
+}
+
+/// Removes the keyboard events if any spinner requested the suppression in the previous pass.
+fn filter_input(ctx: &egui::Context) {
+    let pass_nr = ctx.cumulative_pass_nr();
+
+    let focused_layer = ctx
+        .memory(egui::Memory::focused)
+        .and_then(|focused| ctx.read_response(focused))
+        .map(|response| response.layer_id);
+
+    let blocks: Vec<InputBlock> = ctx.data_mut(|d| {
+        let blocks = d.get_temp_mut_or_default::<InputBlocks>(egui::Id::new(BLOCKS_ID));
+        blocks.blocks.retain(|b| b.pass_nr + 1 >= pass_nr);
+        blocks.blocks.clone()
+    });
+
+    // The widgets inside the modal receive the input as usual
+    let blocks: Vec<InputBlock> = blocks
+        .into_iter()
+        .filter(|b| Some(b.layer) != focused_layer)
+        .collect();
+
+    if blocks.is_empty() {
+        return;
+    }
+
+    let captured: Vec<egui::Id> = ctx.input(|i| {
+        blocks
+            .iter()
+            .filter(|b| i.events.iter().any(|event| b.captures(event)))
+            .map(|b| b.id)
+            .collect()
+    });
+
+    ctx.data_mut(|d| {
+        for id in captured {
+            d.insert_temp(id.with(CAPTURED_KEY_ID), true);
+        }
+    });
+
+    // An event is only passed through if it is allowed by every spinner
+    ctx.input_mut(|i| {
+        i.events
+            .retain(|event| blocks.iter().all(|b| b.allows(event)));
+        i.keys_down
+            .retain(|key| blocks.iter().all(|b| b.allows_key(*key)));
+    });
+}
+
+/// If the event is caused by the keyboard.
+const fn is_keyboard_event(event: &egui::Event) -> bool {
+    matches!(
+        event,
+        egui::Event::Key { .. }
+            | egui::Event::Text(_)
+            | egui::Event::Paste(_)
+            | egui::Event::Copy
+            | egui::Event::Cut
+            | egui::Event::Ime(_)
+    )
+}
+
+/// Splits the given rect into rects that do not overlap with any of the holes.
+pub fn subtract_rects(rect: egui::Rect, holes: &[egui::Rect]) -> Vec<egui::Rect> {
+    let mut parts = vec![rect];
+
+    for hole in holes {
+        parts = parts
+            .into_iter()
+            .flat_map(|part| subtract_rect(part, *hole))
+            .collect();
+    }
+
+    parts
+}
+
+/// Splits the given rect into up to four rects that do not overlap with the hole.
+fn subtract_rect(rect: egui::Rect, hole: egui::Rect) -> Vec<egui::Rect> {
+    let hole = rect.intersect(hole);
+
+    if !hole.is_positive() {
+        return vec![rect];
+    }
+
+    [
+        // Above the hole
+        egui::Rect::from_min_max(rect.min, egui::pos2(rect.max.x, hole.min.y)),
+        // Below the hole
+        egui::Rect::from_min_max(egui::pos2(rect.min.x, hole.max.y), rect.max),
+        // Left of the hole
+        egui::Rect::from_min_max(
+            egui::pos2(rect.min.x, hole.min.y),
+            egui::pos2(hole.min.x, hole.max.y),
+        ),
+        // Right of the hole
+        egui::Rect::from_min_max(
+            egui::pos2(hole.max.x, hole.min.y),
+            egui::pos2(rect.max.x, hole.max.y),
+        ),
+    ]
+    .into_iter()
+    .filter(egui::Rect::is_positive)
+    .collect()
+}
+
+#[test]
+fn test_block_keyboard() {
+    let ctx = egui::Context::default();
+    let id = egui::Id::new("spinner");
+
+    let key_input = || egui::RawInput {
+        events: vec![egui::Event::Text(String::from("a"))],
+        ..Default::default()
+    };
+
+    let _ = ctx.run(key_input(), |ctx| {
+        block_keyboard(ctx, id, egui::LayerId::debug(), &[], None);
+    });
+    let _ = ctx.run(key_input(), |ctx| {
+        assert!(ctx.input(|i| i.events.is_empty()));
+    });
+
+    // The suppression ends once it is no longer requested
+    let _ = ctx.run(key_input(), |ctx| {
+        assert_eq!(ctx.input(|i| i.events.len()), 1);
+    });
+}
+
+#[test]
+fn test_allowed_shortcuts() {
+    let ctx = egui::Context::default();
+    let quit = egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::Q);
+
+    let key_event = |key| egui::Event::Key {
+        key,
+        physical_key: None,
+        pressed: true,
+        repeat: false,
+        modifiers: egui::Modifiers::COMMAND,
+    };
+
+    let key_input = || egui::RawInput {
+        events: vec![key_event(egui::Key::Q), key_event(egui::Key::W)],
+        ..Default::default()
+    };
+
+    let _ = ctx.run(key_input(), |ctx| {
+        block_keyboard(
+            ctx,
+            egui::Id::new("spinner"),
+            egui::LayerId::debug(),
+            &[quit],
+            None,
+        );
+    });
+    let _ = ctx.run(key_input(), |ctx| {
+        assert!(ctx.input_mut(|i| i.consume_shortcut(&quit)));
+        assert!(ctx.input(|i| i.events.is_empty()));
+    });
+}
+
+#[test]
+fn test_subtract_rects() {
+    let rect = egui::Rect::from_min_size(egui::Pos2::ZERO, egui::vec2(100.0, 100.0));
+    let title_bar = egui::Rect::from_min_size(egui::Pos2::ZERO, egui::vec2(100.0, 20.0));
+    let button = egui::Rect::from_min_size(egui::pos2(40.0, 40.0), egui::vec2(20.0, 20.0));
+
+    assert_eq!(subtract_rects(rect, &[]), vec![rect]);
+    assert_eq!(
+        subtract_rects(rect, &[title_bar]),
+        vec![egui::Rect::from_min_max(
+            egui::pos2(0.0, 20.0),
+            egui::pos2(100.0, 100.0)
+        )]
+    );
+
+    let parts = subtract_rects(rect, &[title_bar, button]);
+    assert_eq!(parts.len(), 4);
+    assert!(parts
+        .iter()
+        .all(|p| !p.intersect(title_bar).is_positive() && !p.intersect(button).is_positive()));
+
+    let area: f32 = parts.iter().map(egui::Rect::area).sum();
+    assert!((area - 7600.0).abs() < f32::EPSILON);
+}
@@ -67,11 +67,66 @@
 //!         // This is useful when you want to display the status of the currently running task.
 //!         self.spinner.update_with_content(ctx, |ui| {
 //!             ui.label("Downloading some data...");
-//!         })
+//!         });
 //!     }
 //! }
 //! ```
 //!
+//! # Task runner
+//! Instead of managing the worker thread yourself, you can let the spinner execute the task.
+//! The spinner is opened immediately and closed automatically once the task is finished.
+//!
+//! ```rust
+//! use egui_modal_spinner::{ModalSpinner, SpinnerTask};
+//!
+//! struct MyApp {
+//!     spinner: ModalSpinner,
+//!     task: Option<SpinnerTask<u32>>,
+//! }
+//!
+//! impl MyApp {
+//!     pub fn update(&mut self, ctx: &egui::Context, ui: &mut egui::Ui) {
+//!         if ui.button("Download some data").clicked() {
+//!             // >>> Execute the task in a new thread and open the spinner
+//!             self.task = Some(self.spinner.run(ctx, || {
+//!                 std::thread::sleep(std::time::Duration::from_secs(5));
+//!                 42
+//!             }));
+//!         }
+//!
+//!         if let Some(task) = &mut self.task {
+//!             // >>> The result is returned exactly once after the task is finished
+//!             match task.take_result() {
+//!                 Some(Ok(value)) => println!("Task finished: {value}"),
+//!                 Some(Err(err)) => println!("Task failed: {err}"),
+//!                 None => (),
+//!             }
+//!         }
+//!
+//!         // >>> Update the spinner
+//!         self.spinner.update(ctx);
+//!     }
+//! }
+//! ```
+//!
+//! # Async support
+//! If the `tokio` feature is enabled, a future can be executed using `ModalSpinner::run_future`.
+//! The future is spawned on the given runtime handle and the spinner is closed automatically
+//! once the future resolved.
+//!
+//! ```rust,ignore
+//! self.task = Some(self.spinner.run_future(ctx, runtime.handle(), async {
+//!     download_some_data().await
+//! }));
+//! ```
+//!
+//! # Accessibility
+//! The spinner is exposed to screen readers as a busy progress indicator labeled with the current
+//! status message or stage. The opening of the spinner, the stage changes and the closing of the
+//! spinner are announced using the output events of egui.
+//! If the `accesskit` feature is enabled, the announcements are additionally exposed as an
+//! AccessKit live region.
+//!
 //! # Configuration
 //! The following example shows the possible configuration options.
 //! ```rust
@@ -82,16 +137,72 @@
 //!     .fill_color(egui::Color32::BLUE)
 //!     .fade_in(false)
 //!     .fade_out(true)
+//!     .fade_in_duration(std::time::Duration::from_millis(100))
+//!     .fade_out_duration(std::time::Duration::from_millis(200))
+//!     .easing(egui::emath::easing::cubic_out)
+//!     .transition(egui_modal_spinner::Transition::Fade)
+//!     .blocking(true)
+//!     .suppress_keyboard(true)
+//!     .allowed_shortcuts([egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::Q)])
+//!     .pass_through_rects([egui::Rect::from_min_size(egui::Pos2::ZERO, egui::vec2(800.0, 30.0))])
+//!     .show_delay(std::time::Duration::from_millis(200))
+//!     .min_display_time(std::time::Duration::from_millis(500))
 //!     .spinner_size(40.0)
 //!     .spinner_color(egui::Color32::RED)
-//!     .show_elapsed_time(false);
+//!     .indicator(egui_modal_spinner::DotsIndicator::new().count(3))
+//!     .show_elapsed_time(false)
+//!     .reduced_motion(false)
+//!     .reduced_motion_from_style(false)
+//!     .show_progress_text(true)
+//!     .hide_spinner_on_progress(false)
+//!     .progress_bar_width(200.0)
+//!     .cancellable(true)
+//!     .cancel_button_text("Cancel")
+//!     .cancelling_text("Cancelling...")
+//!     .cancel_key(egui::Key::Escape)
+//!     .confirm_cancel(false)
+//!     .confirm_cancel_text("Cancel the task?")
+//!     .confirm_yes_text("Yes")
+//!     .confirm_no_text("No")
+//!     .timeout(std::time::Duration::from_secs(30))
+//!     .timeout_behavior(egui_modal_spinner::TimeoutBehavior::ShowMessage)
+//!     .timeout_text("This is taking longer than expected")
+//!     .dismiss_button_text("Dismiss")
+//!     .stages(["Loading dogs", "Loading cats", "Loading penguins"])
+//!     .show_card(true)
+//!     .card_max_width(300.0)
+//!     .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+//!     .busy_text("Busy")
+//!     .finished_text("Finished");
 //! ```
 
 #![warn(missing_docs)] // Let's keep the public API well documented!
 
-use std::time::SystemTime;
+mod cancel;
+mod indicator;
+mod input;
+mod progress;
+mod stages;
+mod task;
+mod transition;
+
+pub use cancel::CancellationToken;
+pub use indicator::{
+    ArcIndicator, BarIndicator, DotsIndicator, PulseIndicator, RingIndicator, SpinnerIndicator,
+    SpriteIndicator, TextIndicator,
+};
+pub use progress::ProgressHandle;
+pub use stages::StageHandle;
+pub use task::{SpinnerTask, TaskError};
+pub use transition::Transition;
 
-use egui::Widget;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc, Mutex, PoisonError};
+use std::time::{Duration, SystemTime};
+
+use progress::ProgressUpdate;
+use stages::{StageProgress, StageState};
 
 /// Represents the state the spinner is currently in.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -100,17 +211,136 @@ pub enum SpinnerState {
     Closed,
     /// The spinner is currently open and user input is suppressed.
     Open,
+    /// The user requested the cancellation of the task and the spinner is waiting
+    /// for the user to confirm it. See `ModalSpinner::confirm_cancel`.
+    ConfirmingCancel,
+    /// The user requested the cancellation of the task. The spinner stays open and
+    /// user input is suppressed until the spinner is closed.
+    Cancelling,
+    /// The spinner was closed, but is kept open until the minimum display time has elapsed.
+    /// The work the spinner is waiting on is already done.
+    PendingClose,
+}
+
+/// Represents an event emitted by the spinner during `ModalSpinner::update`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SpinnerEvent {
+    /// The spinner was open longer than the configured timeout.
+    TimedOut,
+    /// The user dismissed the spinner using the dismiss button of the timeout message.
+    Dismissed,
+    /// The user requested the cancellation of the task using the cancel button or the
+    /// cancel key. The spinner is now in `SpinnerState::Cancelling`.
+    CancelRequested,
+}
+
+/// Represents the behavior of the spinner when the configured timeout has elapsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TimeoutBehavior {
+    /// The spinner is closed automatically.
+    Close,
+    /// A message is displayed below the spinner together with a button
+    /// that allows the user to dismiss the spinner.
+    ShowMessage,
+    /// Only `SpinnerEvent::TimedOut` is emitted. The application is responsible
+    /// for handling the timeout.
+    Event,
+}
+
+/// Sense of the modal that blocks the clicks without taking the keyboard focus.
+const NON_FOCUSABLE_CLICK: egui::Sense = egui::Sense {
+    click: true,
+    drag: false,
+    focusable: false,
+};
+
+/// Represents the region of the screen that is covered by the modal.
+#[derive(Debug, Clone, Copy)]
+struct Region {
+    /// The rect covered by the modal background.
+    rect: egui::Rect,
+    /// The layer the modal is placed directly above.
+    /// If None, the modal is placed above all other layers.
+    parent_layer: Option<egui::LayerId>,
+    /// The rounding of the modal background.
+    rounding: egui::Rounding,
+    /// If the region covers the whole screen.
+    /// Keyboard events are only swallowed if the whole screen is covered.
+    covers_screen: bool,
+}
+
+/// State of the spinner that is shared with the worker through tokens and handles.
+///
+/// Cloning creates a new state, so that a cloned spinner is independent of the original
+/// spinner. Only the progress of the stages is copied.
+#[derive(Debug)]
+struct SharedState {
+    /// Token used to notify the worker that the user requested the cancellation of the task.
+    cancellation_token: CancellationToken,
+    /// Progress of the stages shared with the stage handles.
+    stage_progress: Arc<Mutex<StageProgress>>,
+    /// Sender used to create new progress handles.
+    progress_tx: mpsc::Sender<ProgressUpdate>,
+    /// Receiver of the updates sent by the progress handles.
+    progress_rx: Mutex<mpsc::Receiver<ProgressUpdate>>,
+}
+
+impl SharedState {
+    fn new() -> Self {
+        let (progress_tx, progress_rx) = mpsc::channel();
+
+        Self {
+            cancellation_token: CancellationToken::new(),
+            stage_progress: Arc::new(Mutex::new(StageProgress::default())),
+            progress_tx,
+            progress_rx: Mutex::new(progress_rx),
+        }
+    }
+}
+
+impl Clone for SharedState {
+    fn clone(&self) -> Self {
+        let state = Self::new();
+        *stages::lock(&state.stage_progress) = stages::lock(&self.stage_progress).clone();
+        state
+    }
 }
 
 /// Represents a spinner instance.
+///
+/// A cloned spinner is independent of the original spinner. It uses a new cancellation token
+/// and does not receive the updates of the handles obtained from the original spinner.
 #[derive(Debug, Clone)]
 pub struct ModalSpinner {
     /// Represents the state of the spinner.
     state: SpinnerState,
     /// If the modal is closed but currently fading out.
     fading_out: bool,
+    /// Progress of the fade in animation in the last frame the modal was open.
+    fade_in_progress: f32,
     /// Timestamp when the spinner was opened.
     timestamp: SystemTime,
+    /// Flag of the task started with `ModalSpinner::run` that is set once the task is finished.
+    task_finished: Option<Arc<AtomicBool>>,
+    /// State shared with the worker through the cancellation token and the handles.
+    shared: SharedState,
+    /// If the timeout has elapsed since opening the spinner.
+    timed_out: bool,
+    /// Size of the spinner content measured in the previous frame.
+    /// Used to center the content on the screen.
+    content_size: Option<egui::Vec2>,
+    /// The widget that had the keyboard focus before it was surrendered by the modal.
+    /// The focus is restored once the spinner is closed.
+    previous_focus: Option<egui::Id>,
+    /// If the opening of the spinner was announced to assistive technology.
+    announced: bool,
+    /// The stage that was last announced to assistive technology.
+    announced_stage: Option<usize>,
+    /// The last announcement together with the time it was made.
+    #[cfg(feature = "accesskit")]
+    announcement: Option<(String, SystemTime)>,
+    /// Events that happened but were not yet returned by an update method.
+    pending_events: VecDeque<SpinnerEvent>,
 
     /// The ID of the modal area. If None, a default is used.
     id: Option<egui::Id>,
@@ -120,10 +350,94 @@ pub struct ModalSpinner {
     fade_in: bool,
     /// If the modal should fade out when closing.
     fade_out: bool,
+    /// Duration of the fade in animation. If None, the animation time of the egui style is used.
+    fade_in_duration: Option<Duration>,
+    /// Duration of the fade out animation. If None, the animation time of the egui style is used.
+    fade_out_duration: Option<Duration>,
+    /// Easing function applied to the fade animations.
+    easing: fn(f32) -> f32,
+    /// The transition used when opening and closing the modal.
+    transition: Transition,
+    /// If user input should be suppressed while the spinner is open.
+    blocking: bool,
+    /// If keyboard input should be suppressed while the spinner is open.
+    /// Only has an effect if the spinner is blocking.
+    suppress_keyboard: bool,
+    /// Keyboard shortcuts that are not suppressed while the spinner is open.
+    allowed_shortcuts: Vec<egui::KeyboardShortcut>,
+    /// Screen rects in which user input is not suppressed while the spinner is open.
+    pass_through_rects: Vec<egui::Rect>,
+    /// Time after opening before the modal becomes visible.
+    /// User input is suppressed immediately after opening.
+    show_delay: Duration,
+    /// Minimum time the modal is visible before it is closed.
+    min_display_time: Duration,
     /// Configuration of the spinner.
     spinner: Spinner,
     /// If the time elapsed since opening should be displayed under the spinner.
     show_elapsed_time: bool,
+    /// If animations should be reduced.
+    reduced_motion: bool,
+    /// If animations should be reduced when the animation time of the egui style is zero.
+    reduced_motion_from_style: bool,
+
+    /// The current progress of the task in the range `0.0..=1.0`.
+    /// If None, the spinner is in indeterminate mode and no progress bar is shown.
+    progress: Option<f32>,
+    /// Status message displayed below the spinner.
+    status_message: Option<String>,
+    /// If the progress percentage should be displayed inside the progress bar.
+    show_progress_text: bool,
+    /// If the spinner should be hidden while a progress is set.
+    hide_spinner_on_progress: bool,
+    /// The width of the progress bar.
+    progress_bar_width: f32,
+
+    /// If a cancel button should be displayed below the spinner.
+    cancellable: bool,
+    /// The text of the cancel button.
+    cancel_button_text: String,
+    /// The text displayed while waiting for the worker to acknowledge the cancellation.
+    cancelling_text: String,
+    /// The key that requests the cancellation of the task. If None, no key is used.
+    cancel_key: Option<egui::Key>,
+    /// If the user must confirm the cancellation before the task is cancelled.
+    confirm_cancel: bool,
+    /// The question displayed when the user must confirm the cancellation.
+    confirm_cancel_text: String,
+    /// The text of the button that confirms the cancellation.
+    confirm_yes_text: String,
+    /// The text of the button that aborts the cancellation.
+    confirm_no_text: String,
+
+    /// Time after opening before the spinner times out. If None, the spinner never times out.
+    timeout: Option<Duration>,
+    /// The behavior of the spinner when the timeout has elapsed.
+    timeout_behavior: TimeoutBehavior,
+    /// The message displayed when the timeout has elapsed.
+    timeout_text: String,
+    /// The text of the button to dismiss the spinner after the timeout has elapsed.
+    dismiss_button_text: String,
+
+    /// Names of the stages of the task displayed as a checklist below the spinner.
+    stages: Vec<String>,
+
+    /// If the spinner and the additional content should be displayed inside a card.
+    show_card: bool,
+    /// The frame of the card. If None, the window frame of the current style is used.
+    card_frame: Option<egui::Frame>,
+    /// The maximum width of the card.
+    card_max_width: Option<f32>,
+
+    /// The position of the spinner and the additional content inside the blocked region.
+    anchor: egui::Align2,
+    /// The offset added to the anchored position.
+    anchor_offset: egui::Vec2,
+
+    /// The text describing the busy state to assistive technology.
+    busy_text: String,
+    /// The text announced to assistive technology once the spinner is closed.
+    finished_text: String,
 }
 
 impl Default for ModalSpinner {
@@ -139,14 +453,69 @@ impl ModalSpinner {
         Self {
             state: SpinnerState::Closed,
             fading_out: false,
+            fade_in_progress: 0.0,
             timestamp: SystemTime::now(),
+            task_finished: None,
+            shared: SharedState::new(),
+            timed_out: false,
+            content_size: None,
+            previous_focus: None,
+            announced: false,
+            announced_stage: None,
+            #[cfg(feature = "accesskit")]
+            announcement: None,
+            pending_events: VecDeque::new(),
 
             id: None,
             fill_color: None,
             fade_in: true,
             fade_out: true,
+            fade_in_duration: None,
+            fade_out_duration: None,
+            easing: egui::emath::easing::cubic_out,
+            transition: Transition::Fade,
+            blocking: true,
+            suppress_keyboard: true,
+            allowed_shortcuts: Vec::new(),
+            pass_through_rects: Vec::new(),
+            show_delay: Duration::ZERO,
+            min_display_time: Duration::ZERO,
             spinner: Spinner::default(),
             show_elapsed_time: true,
+            reduced_motion: false,
+            reduced_motion_from_style: false,
+
+            progress: None,
+            status_message: None,
+            show_progress_text: true,
+            hide_spinner_on_progress: false,
+            progress_bar_width: 200.0,
+
+            cancellable: false,
+            cancel_button_text: String::from("Cancel"),
+            cancelling_text: String::from("Cancelling..."),
+            cancel_key: Some(egui::Key::Escape),
+            confirm_cancel: false,
+            confirm_cancel_text: String::from("Cancel the task?"),
+            confirm_yes_text: String::from("Yes"),
+            confirm_no_text: String::from("No"),
+
+            timeout: None,
+            timeout_behavior: TimeoutBehavior::ShowMessage,
+            timeout_text: String::from("This is taking longer than expected"),
+            dismiss_button_text: String::from("Dismiss"),
+
+            stages: Vec::new(),
+
+            show_card: false,
+            card_frame: None,
+            card_max_width: None,
+
+            anchor: egui::Align2::CENTER_CENTER,
+            anchor_offset: egui::Vec2::ZERO,
+
+            busy_text: String::from("Busy"),
+            finished_text: String::from("Finished"),
         }
     }
 
@@ -174,6 +543,122 @@ impl ModalSpinner {
         self
     }
 
+    /// Sets the duration of the fade in animation.
+    /// By default, the animation time of the egui style is used.
+    pub const fn fade_in_duration(mut self, duration: Duration) -> Self {
+        self.fade_in_duration = Some(duration);
+        self
+    }
+
+    /// Sets the duration of the fade out animation.
+    /// By default, the animation time of the egui style is used.
+    pub const fn fade_out_duration(mut self, duration: Duration) -> Self {
+        self.fade_out_duration = Some(duration);
+        self
+    }
+
+    /// Sets the easing function applied to the fade animations, for example one of the
+    /// functions of `egui::emath::easing`. By default, `egui::emath::easing::cubic_out` is used.
+    pub const fn easing(mut self, easing: fn(f32) -> f32) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Sets the transition used when opening and closing the modal.
+    /// The transition is animated using the configured fade durations and easing function.
+    ///
+    /// By default, `Transition::Fade` is used.
+    pub const fn transition(mut self, transition: Transition) -> Self {
+        self.transition = transition;
+        self
+    }
+
+    /// If animations should be reduced for users with motion sensitivity.
+    ///
+    /// If enabled, the fade animations are disabled and the configured indicator is replaced by
+    /// a slowly pulsing `PulseIndicator`. The busy state is additionally communicated using the
+    /// text configured with `ModalSpinner::busy_text` if no status message is set.
+    ///
+    /// Disabled by default.
+    pub const fn reduced_motion(mut self, reduced_motion: bool) -> Self {
+        self.reduced_motion = reduced_motion;
+        self
+    }
+
+    /// If reduced motion should be enabled automatically while the animation time
+    /// of the egui style is zero. See `ModalSpinner::reduced_motion`.
+    ///
+    /// Disabled by default.
+    pub const fn reduced_motion_from_style(mut self, from_style: bool) -> Self {
+        self.reduced_motion_from_style = from_style;
+        self
+    }
+
+    /// If user input should be suppressed while the spinner is open.
+    ///
+    /// If disabled, the spinner is displayed as a passive busy indicator without a
+    /// modal background and the user can continue to interact with the application.
+    /// The spinner itself is not interactable in this case, so the cancel and dismiss
+    /// buttons are not displayed.
+    pub const fn blocking(mut self, blocking: bool) -> Self {
+        self.blocking = blocking;
+        self
+    }
+
+    /// If keyboard input should be suppressed while the spinner is open.
+    ///
+    /// If enabled, widgets behind the modal lose the keyboard focus and the focus is
+    /// restored once the spinner is closed. Additionally, key events, text input and
+    /// keyboard shortcuts are swallowed if the modal covers the whole screen.
+    /// Only has an effect if the spinner is blocking.
+    ///
+    /// Enabled by default.
+    pub const fn suppress_keyboard(mut self, suppress_keyboard: bool) -> Self {
+        self.suppress_keyboard = suppress_keyboard;
+        self
+    }
+
+    /// Sets the keyboard shortcuts that are not suppressed while the spinner is open,
+    /// for example a global shortcut to quit the application.
+    pub fn allowed_shortcuts(
+        mut self,
+        shortcuts: impl IntoIterator<Item = egui::KeyboardShortcut>,
+    ) -> Self {
+        self.allowed_shortcuts = shortcuts.into_iter().collect();
+        self
+    }
+
+    /// Sets the screen rects in which user input is not suppressed while the spinner is open,
+    /// for example the drag area of a custom title bar.
+    ///
+    /// The modal background is not displayed inside the rects. Keyboard input is passed through
+    /// if the focused widget is located inside one of the rects.
+    /// Rects overlapping the spinner content are still covered by the content.
+    /// See `ModalSpinner::set_pass_through_rects` to update the rects while the spinner is open.
+    pub fn pass_through_rects(mut self, rects: impl IntoIterator<Item = egui::Rect>) -> Self {
+        self.pass_through_rects = rects.into_iter().collect();
+        self
+    }
+
+    /// Sets the time after opening before the modal background and the spinner become visible.
+    ///
+    /// User input is suppressed immediately after opening. This avoids flickering of the
+    /// modal for tasks that finish quickly.
+    pub const fn show_delay(mut self, delay: Duration) -> Self {
+        self.show_delay = delay;
+        self
+    }
+
+    /// Sets the minimum time the modal is visible once it has appeared.
+    ///
+    /// If the spinner is closed before the minimum time has elapsed, it is put into
+    /// `SpinnerState::PendingClose` and closed once the time has elapsed.
+    /// This avoids the modal being visible for only a few frames.
+    pub const fn min_display_time(mut self, min_display_time: Duration) -> Self {
+        self.min_display_time = min_display_time;
+        self
+    }
+
     /// Sets the size of the spinner.
     pub const fn spinner_size(mut self, size: f32) -> Self {
         self.spinner.size = Some(size);
@@ -186,174 +671,1899 @@ impl ModalSpinner {
         self
     }
 
+    /// Sets the indicator painted by the spinner.
+    ///
+    /// The default is `ArcIndicator`, which looks the same as `egui::Spinner`.
+    /// Use one of the other indicators provided by this crate or implement
+    /// `SpinnerIndicator` for a custom indicator.
+    pub fn indicator(mut self, indicator: impl SpinnerIndicator + 'static) -> Self {
+        self.spinner.indicator = Arc::new(indicator);
+        self
+    }
+
     /// If the elapsed time should be displayed below the spinner.
     pub const fn show_elapsed_time(mut self, show_elapsed_time: bool) -> Self {
         self.show_elapsed_time = show_elapsed_time;
         self
     }
-}
 
-/// Getter and setter
-impl ModalSpinner {
-    /// Gets the current state of the spinner.
-    pub const fn state(&self) -> &SpinnerState {
-        &self.state
+    /// If the progress percentage should be displayed inside the progress bar.
+    pub const fn show_progress_text(mut self, show_progress_text: bool) -> Self {
+        self.show_progress_text = show_progress_text;
+        self
     }
-}
 
-/// Implementation methods
-impl ModalSpinner {
-    /// Opens the spinner.
-    pub fn open(&mut self) {
-        self.state = SpinnerState::Open;
-        self.timestamp = SystemTime::now();
+    /// If the spinner should be hidden while a progress is set.
+    /// In this case, only the progress bar is displayed.
+    pub const fn hide_spinner_on_progress(mut self, hide_spinner_on_progress: bool) -> Self {
+        self.hide_spinner_on_progress = hide_spinner_on_progress;
+        self
     }
 
-    /// Closes the spinner.
-    pub fn close(&mut self) {
-        self.state = SpinnerState::Closed;
-        self.fading_out = self.fade_out;
+    /// Sets the width of the progress bar.
+    pub const fn progress_bar_width(mut self, width: f32) -> Self {
+        self.progress_bar_width = width;
+        self
     }
 
-    /// Main update method of the spinner that should be called every frame if you want the
-    /// spinner to be visible.
+    /// If a cancel button should be displayed below the spinner.
     ///
-    /// This has no effect if the `SpinnerState` is currently not `SpinnerState::Open`.
-    pub fn update(&mut self, ctx: &egui::Context) {
-        self.update_ui(ctx, |_| ());
+    /// Clicking the button cancels the token returned by `ModalSpinner::cancellation_token`
+    /// and puts the spinner into `SpinnerState::Cancelling` until it is closed.
+    pub const fn cancellable(mut self, cancellable: bool) -> Self {
+        self.cancellable = cancellable;
+        self
     }
 
-    /// Main update method of the spinner that should be called every frame if you want the
-    /// spinner to be visible.
+    /// Sets the text of the cancel button.
+    pub fn cancel_button_text(mut self, text: impl Into<String>) -> Self {
+        self.cancel_button_text = text.into();
+        self
+    }
+
+    /// Sets the text displayed while waiting for the worker to acknowledge the cancellation.
+    pub fn cancelling_text(mut self, text: impl Into<String>) -> Self {
+        self.cancelling_text = text.into();
+        self
+    }
+
+    /// Sets the key that requests the cancellation of the task, just like the cancel button.
+    /// Pressing the key while the user is asked to confirm the cancellation aborts the
+    /// cancellation. Pass None to disable the key.
     ///
-    /// This method allows additional content to be displayed under the
-    /// spinner - or if activated - under the elapsed time.
-    /// However, note that the additional content is not taken into account when
-    /// centering the spinner. Therefore, a large amount of additional
-    /// content on the Y-axis is not recommended.
+    /// Only has an effect if the spinner is cancellable and blocking.
+    /// By default, `egui::Key::Escape` is used.
+    pub fn cancel_key(mut self, key: impl Into<Option<egui::Key>>) -> Self {
+        self.cancel_key = key.into();
+        self
+    }
+
+    /// If the user must confirm the cancellation before the task is cancelled.
     ///
-    /// This has no effect if the `SpinnerState` is currently not `SpinnerState::Open`.
-    pub fn update_with_content(&mut self, ctx: &egui::Context, ui: impl FnOnce(&mut egui::Ui)) {
-        self.update_ui(ctx, ui);
+    /// If enabled, requesting the cancellation puts the spinner into
+    /// `SpinnerState::ConfirmingCancel` and a confirmation question is displayed
+    /// instead of the cancel button.
+    pub const fn confirm_cancel(mut self, confirm_cancel: bool) -> Self {
+        self.confirm_cancel = confirm_cancel;
+        self
     }
-}
 
-/// UI methods
-impl ModalSpinner {
-    fn update_ui(&mut self, ctx: &egui::Context, content: impl FnOnce(&mut egui::Ui)) {
-        if self.state != SpinnerState::Open && !self.fading_out {
-            return;
-        }
+    /// Sets the question displayed when the user must confirm the cancellation.
+    pub fn confirm_cancel_text(mut self, text: impl Into<String>) -> Self {
+        self.confirm_cancel_text = text.into();
+        self
+    }
 
-        let id = self.id.unwrap_or_else(|| egui::Id::from("_modal_spinner"));
-        let screen_rect = ctx.input(|i| i.screen_rect);
+    /// Sets the text of the button that confirms the cancellation.
+    pub fn confirm_yes_text(mut self, text: impl Into<String>) -> Self {
+        self.confirm_yes_text = text.into();
+        self
+    }
 
-        let opacity = ctx.animate_bool_with_easing(
-            id.with("fade_out"),
-            self.state == SpinnerState::Open,
-            egui::emath::easing::cubic_out,
-        );
+    /// Sets the text of the button that aborts the cancellation.
+    pub fn confirm_no_text(mut self, text: impl Into<String>) -> Self {
+        self.confirm_no_text = text.into();
+        self
+    }
 
-        if opacity <= 0.0 && self.fading_out {
-            self.fading_out = false;
-            return;
-        }
+    /// Sets the time after opening before the spinner times out.
+    /// What happens after the timeout is configured with `ModalSpinner::timeout_behavior`.
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
 
-        let re = egui::Area::new(id)
-            .movable(false)
-            .interactable(true)
-            .fixed_pos(screen_rect.left_top())
-            .fade_in(self.fade_in)
-            .show(ctx, |ui| {
-                if self.fading_out {
-                    ui.multiply_opacity(opacity);
-                }
+    /// Sets the behavior of the spinner when the timeout has elapsed.
+    /// `SpinnerEvent::TimedOut` is returned by `ModalSpinner::update` regardless of the behavior.
+    pub const fn timeout_behavior(mut self, behavior: TimeoutBehavior) -> Self {
+        self.timeout_behavior = behavior;
+        self
+    }
+
+    /// Sets the message displayed when the timeout has elapsed.
+    /// Only used with `TimeoutBehavior::ShowMessage`.
+    pub fn timeout_text(mut self, text: impl Into<String>) -> Self {
+        self.timeout_text = text.into();
+        self
+    }
 
-                let fill_color = self.fill_color.unwrap_or_else(|| {
-                    if ctx.style().visuals.dark_mode {
-                        egui::Color32::from_black_alpha(120)
-                    } else {
-                        egui::Color32::from_white_alpha(40)
-                    }
-                });
+    /// Sets the text of the button to dismiss the spinner after the timeout has elapsed.
+    /// Only used with `TimeoutBehavior::ShowMessage`.
+    pub fn dismiss_button_text(mut self, text: impl Into<String>) -> Self {
+        self.dismiss_button_text = text.into();
+        self
+    }
 
-                ui.painter()
-                    .rect_filled(screen_rect, egui::Rounding::ZERO, fill_color);
+    /// Sets the stages of the task.
+    ///
+    /// The stages are displayed as a checklist below the spinner and can be advanced
+    /// from a worker using the handle returned by `ModalSpinner::stage_handle`.
+    /// The first stage is started when the spinner is opened.
+    pub fn stages(mut self, stages: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.stages = stages.into_iter().map(Into::into).collect();
+        self
+    }
 
-                ui.allocate_response(screen_rect.size(), egui::Sense::click());
+    /// If the spinner and the additional content should be displayed inside a card.
+    /// This keeps the content readable on busy backgrounds.
+    pub const fn show_card(mut self, show_card: bool) -> Self {
+        self.show_card = show_card;
+        self
+    }
 
-                let child_ui = egui::UiBuilder::new()
-                    .max_rect(screen_rect)
-                    .layout(egui::Layout::top_down(egui::Align::Center));
+    /// Sets the frame of the card, for example to configure the rounding, shadow,
+    /// inner margin, fill or stroke.
+    ///
+    /// By default, `egui::Frame::window` of the current style is used.
+    pub const fn card_frame(mut self, frame: egui::Frame) -> Self {
+        self.card_frame = Some(frame);
+        self
+    }
 
-                ui.allocate_new_ui(child_ui, |ui| {
-                    self.ui_update_spinner(ui, &screen_rect);
-                    content(ui);
-                });
-            });
+    /// Sets the maximum width of the card.
+    pub const fn card_max_width(mut self, max_width: f32) -> Self {
+        self.card_max_width = Some(max_width);
+        self
+    }
 
-        ctx.move_to_top(re.response.layer_id);
+    /// Sets the position of the spinner and the additional content inside the blocked region.
+    ///
+    /// The offset is added to the anchored position, e.g. use
+    /// `anchor(egui::Align2::RIGHT_BOTTOM, egui::vec2(-10.0, -10.0))` to display the spinner
+    /// in the bottom right corner with a small margin. The content is always kept
+    /// inside the blocked region.
+    ///
+    /// By default, the content is centered.
+    pub fn anchor(mut self, align: egui::Align2, offset: impl Into<egui::Vec2>) -> Self {
+        self.anchor = align;
+        self.anchor_offset = offset.into();
+        self
     }
 
-    fn ui_update_spinner(&self, ui: &mut egui::Ui, screen_rect: &egui::Rect) {
-        let spinner_h = self
-            .spinner
-            .size
-            .unwrap_or_else(|| ui.style().spacing.interact_size.y);
+    /// Sets the text describing the busy state to assistive technology.
+    ///
+    /// The text is announced once the spinner becomes visible and is used as the label of
+    /// the spinner if no status message is set and no stage is active.
+    pub fn busy_text(mut self, text: impl Into<String>) -> Self {
+        self.busy_text = text.into();
+        self
+    }
 
-        let mut margin = screen_rect.height() / 2.0 - spinner_h / 2.0;
+    /// Sets the text announced to assistive technology once the spinner is closed.
+    pub fn finished_text(mut self, text: impl Into<String>) -> Self {
+        self.finished_text = text.into();
+        self
+    }
+}
 
-        if self.show_elapsed_time {
-            let height = ui.fonts(|f| f.row_height(&egui::TextStyle::Body.resolve(ui.style())));
-            margin -= ui.spacing().item_spacing.y.mul_add(2.0, height / 2.0);
-        }
+/// Getter and setter
+impl ModalSpinner {
+    /// Gets the current state of the spinner.
+    pub const fn state(&self) -> &SpinnerState {
+        &self.state
+    }
+
+    /// If the spinner is currently open and suppressing user input.
+    pub const fn is_open(&self) -> bool {
+        matches!(
+            self.state,
+            SpinnerState::Open
+                | SpinnerState::ConfirmingCancel
+                | SpinnerState::Cancelling
+                | SpinnerState::PendingClose
+        )
+    }
 
-        ui.add_space(margin);
+    /// Gets the cancellation token of the spinner.
+    ///
+    /// The token should be passed to the worker, which is responsible for regularly checking
+    /// if the cancellation was requested. The token is replaced with a new one when the
+    /// spinner is closed after a cancellation.
+    pub fn cancellation_token(&self) -> CancellationToken {
+        self.shared.cancellation_token.clone()
+    }
+
+    /// Gets a handle that can be used to advance the stages of the spinner from any thread.
+    ///
+    /// The handle stays valid when the spinner is opened again.
+    pub fn stage_handle(&self) -> StageHandle {
+        StageHandle::new(self.shared.stage_progress.clone())
+    }
 
-        self.spinner.update(ui);
+    /// Gets the index of the currently active stage.
+    /// Returns None if no stages are configured or all stages are finished.
+    pub fn current_stage(&self) -> Option<usize> {
+        stages::lock(&self.shared.stage_progress).current()
+    }
 
-        if self.show_elapsed_time {
-            self.ui_update_elapsed_time(ui);
+    /// Gets the current progress of the spinner.
+    /// Returns None if the spinner is in indeterminate mode.
+    pub const fn progress(&self) -> Option<f32> {
+        self.progress
+    }
+
+    /// Sets the progress of the currently running task and switches the spinner
+    /// into determinate mode. A progress bar is displayed below the spinner.
+    ///
+    /// The value is clamped to the range `0.0..=1.0`. Non-finite values are ignored.
+    pub fn set_progress(&mut self, progress: f32) {
+        if progress.is_finite() {
+            self.progress = Some(progress.clamp(0.0, 1.0));
         }
     }
 
-    fn ui_update_elapsed_time(&self, ui: &mut egui::Ui) {
-        ui.add_space(ui.spacing().item_spacing.y);
-        ui.label(format!(
-            "Elapsed: {} s",
-            self.timestamp.elapsed().unwrap_or_default().as_secs()
-        ));
+    /// Removes the current progress and switches the spinner back into
+    /// indeterminate mode.
+    pub fn clear_progress(&mut self) {
+        self.progress = None;
     }
-}
 
-/// This tests if the spinner is send and sync.
-#[cfg(test)]
-const fn test_prop<T: Send + Sync>() {}
+    /// Gets the current status message of the spinner.
+    pub fn status_message(&self) -> Option<&str> {
+        self.status_message.as_deref()
+    }
 
-#[test]
-const fn test() {
-    test_prop::<ModalSpinner>();
-}
+    /// Sets the status message displayed below the spinner.
+    pub fn set_status_message(&mut self, message: impl Into<String>) {
+        self.status_message = Some(message.into());
+    }
 
-/// Wrapper above `egui::Spinner` to be able to customize trait implementations.
-#[derive(Debug, Default, Clone, PartialEq)]
-struct Spinner {
-    pub size: Option<f32>,
-    pub color: Option<egui::Color32>,
+    /// Removes the current status message.
+    pub fn clear_status_message(&mut self) {
+        self.status_message = None;
+    }
+
+    /// Sets the screen rects in which user input is not suppressed.
+    /// See `ModalSpinner::pass_through_rects`.
+    pub fn set_pass_through_rects(&mut self, rects: impl IntoIterator<Item = egui::Rect>) {
+        self.pass_through_rects = rects.into_iter().collect();
+    }
+
+    /// Gets a handle that can be used to report the progress of the task from any thread.
+    ///
+    /// The handle can set the status message, the progress and the active stage.
+    /// The updates are applied the next time the spinner is updated. Every update requests
+    /// a repaint of the given context.
+    pub fn progress_handle(&self, ctx: &egui::Context) -> ProgressHandle {
+        ProgressHandle::new(self.shared.progress_tx.clone(), ctx.clone())
+    }
 }
 
-impl Spinner {
-    fn update(&self, ui: &mut egui::Ui) -> egui::Response {
-        let mut spinner = egui::Spinner::new();
+/// Implementation methods
+impl ModalSpinner {
+    /// Opens the spinner.
+    ///
+    /// The progress and the status message of the previous run are removed.
+    pub fn open(&mut self) {
+        self.state = SpinnerState::Open;
+        self.timestamp = SystemTime::now();
+        self.timed_out = false;
+        self.fading_out = false;
+        self.fade_in_progress = 0.0;
+        self.progress = None;
+        self.status_message = None;
 
-        if let Some(size) = self.size {
-            spinner = spinner.size(size);
+        stages::lock(&self.shared.stage_progress).reset(self.stages.len());
+    }
+
+    /// Closes the spinner.
+    ///
+    /// If the modal is visible for less than the minimum display time, the spinner is put into
+    /// `SpinnerState::PendingClose` and closed once the time has elapsed.
+    pub fn close(&mut self) {
+        if self.is_open() && !self.remaining_display_time().is_zero() {
+            self.state = SpinnerState::PendingClose;
+            self.task_finished = None;
+            return;
         }
 
-        if let Some(color) = self.color {
-            spinner = spinner.color(color);
+        self.state = SpinnerState::Closed;
+        self.fading_out = self.fade_out && self.is_delay_elapsed();
+        self.task_finished = None;
+
+        if self.shared.cancellation_token.is_cancelled() {
+            self.shared.cancellation_token = CancellationToken::new();
+        }
+    }
+
+    /// Executes the given closure in a new thread and opens the spinner.
+    ///
+    /// The spinner is closed automatically in `ModalSpinner::update` once the task is finished.
+    /// The result of the task can be taken from the returned `SpinnerTask`.
+    /// If the closure panics, the panic is reported as `TaskError::Panicked` instead.
+    pub fn run<T, F>(&mut self, ctx: &egui::Context, task: F) -> SpinnerTask<T>
+    where
+        T: Send + 'static,
+        F: FnOnce() -> T + Send + 'static,
+    {
+        // The spinner is opened first, so that the stages are not reset after the
+        // worker already advanced them
+        self.open();
+
+        let (task, finished) = SpinnerTask::spawn(ctx, task);
+        self.task_finished = Some(finished);
+
+        task
+    }
+
+    /// Requests the cancellation of the task.
+    ///
+    /// The cancellation token is cancelled and the spinner is put into
+    /// `SpinnerState::Cancelling`. The spinner stays open until it is closed.
+    /// This has no effect if the spinner is currently not open or already pending to close.
+    pub fn cancel(&mut self) {
+        if !matches!(
+            self.state,
+            SpinnerState::Open | SpinnerState::ConfirmingCancel | SpinnerState::Cancelling
+        ) {
+            return;
+        }
+
+        self.shared.cancellation_token.cancel();
+        self.state = SpinnerState::Cancelling;
+    }
+
+    /// Spawns the given future on the tokio runtime of the given handle and opens the spinner.
+    ///
+    /// The spinner is closed automatically in `ModalSpinner::update` once the future resolved.
+    /// The output of the future can be taken from the returned `SpinnerTask`.
+    /// If the future panics, the panic is reported as `TaskError::Panicked` instead.
+    #[cfg(feature = "tokio")]
+    pub fn run_future<T, F>(
+        &mut self,
+        ctx: &egui::Context,
+        handle: &tokio::runtime::Handle,
+        future: F,
+    ) -> SpinnerTask<T>
+    where
+        T: Send + 'static,
+        F: std::future::Future<Output = T> + Send + 'static,
+    {
+        self.open();
+
+        let (task, finished) = SpinnerTask::spawn_future(ctx, handle, future);
+        self.task_finished = Some(finished);
+
+        task
+    }
+
+    /// Main update method of the spinner that should be called every frame if you want the
+    /// spinner to be visible.
+    ///
+    /// Returns an event if something happened that the application may want to react to.
+    /// If multiple events happened in the same frame, the remaining events are returned
+    /// by the following updates.
+    ///
+    /// This has no effect if the spinner is currently not open.
+    pub fn update(&mut self, ctx: &egui::Context) -> Option<SpinnerEvent> {
+        let region = Self::screen_region(ctx);
+        self.update_ui(ctx, region, |_| ())
+    }
+
+    /// Main update method of the spinner that should be called every frame if you want the
+    /// spinner to be visible.
+    ///
+    /// This method allows additional content to be displayed under the
+    /// spinner - or if activated - under the elapsed time.
+    /// The additional content is taken into account when centering the spinner.
+    /// If the content is larger than the screen, it is displayed inside a scroll area.
+    ///
+    /// Returns an event if something happened that the application may want to react to.
+    /// If multiple events happened in the same frame, the remaining events are returned
+    /// by the following updates.
+    ///
+    /// This has no effect if the spinner is currently not open.
+    pub fn update_with_content(
+        &mut self,
+        ctx: &egui::Context,
+        ui: impl FnOnce(&mut egui::Ui),
+    ) -> Option<SpinnerEvent> {
+        let region = Self::screen_region(ctx);
+        self.update_ui(ctx, region, ui)
+    }
+
+    /// Update method of the spinner that only covers the given rect instead of the whole screen.
+    /// User input is only suppressed inside the rect and the spinner is centered in the rect.
+    ///
+    /// The modal is placed above all other layers.
+    /// Use `ModalSpinner::update_in_ui` to block only a single panel.
+    ///
+    /// This has no effect if the spinner is currently not open.
+    pub fn update_over_rect(
+        &mut self,
+        ctx: &egui::Context,
+        rect: egui::Rect,
+    ) -> Option<SpinnerEvent> {
+        self.update_over_rect_with_content(ctx, rect, |_| ())
+    }
+
+    /// Same as `ModalSpinner::update_over_rect`, but allows additional content to be displayed
+    /// under the spinner. See `ModalSpinner::update_with_content`.
+    pub fn update_over_rect_with_content(
+        &mut self,
+        ctx: &egui::Context,
+        rect: egui::Rect,
+        ui: impl FnOnce(&mut egui::Ui),
+    ) -> Option<SpinnerEvent> {
+        let region = Region {
+            rect,
+            parent_layer: None,
+            rounding: egui::Rounding::ZERO,
+            covers_screen: false,
+        };
+
+        self.update_ui(ctx, region, ui)
+    }
+
+    /// Update method of the spinner that only covers the given `egui::Ui`, for example a
+    /// single panel. User input is only suppressed inside the UI and the spinner is
+    /// centered in the UI.
+    ///
+    /// The modal is placed directly above the layer of the UI. Windows and other areas above the
+    /// UI stay usable.
+    ///
+    /// This has no effect if the spinner is currently not open.
+    pub fn update_in_ui(&mut self, ui: &egui::Ui) -> Option<SpinnerEvent> {
+        self.update_in_ui_with_content(ui, |_| ())
+    }
+
+    /// Same as `ModalSpinner::update_in_ui`, but allows additional content to be displayed
+    /// under the spinner. See `ModalSpinner::update_with_content`.
+    pub fn update_in_ui_with_content(
+        &mut self,
+        ui: &egui::Ui,
+        content: impl FnOnce(&mut egui::Ui),
+    ) -> Option<SpinnerEvent> {
+        let region = Region {
+            rect: ui.max_rect(),
+            parent_layer: Some(ui.layer_id()),
+            rounding: egui::Rounding::ZERO,
+            covers_screen: false,
+        };
+
+        self.update_ui(ui.ctx(), region, content)
+    }
+
+    /// Update method of the spinner that only covers a single `egui::Window`.
+    /// User input is only suppressed inside the window and the spinner is
+    /// centered in the window.
+    ///
+    /// The given response must be the response of the window returned by `egui::Window::show`.
+    /// The modal is placed directly above the layer of the window and follows the window
+    /// when it is moved or resized. This method must therefore be called after the window
+    /// is shown in every frame.
+    ///
+    /// This has no effect if the spinner is currently not open.
+    pub fn update_over_window(
+        &mut self,
+        ctx: &egui::Context,
+        window: &egui::Response,
+    ) -> Option<SpinnerEvent> {
+        self.update_over_window_with_content(ctx, window, |_| ())
+    }
+
+    /// Same as `ModalSpinner::update_over_window`, but allows additional content to be displayed
+    /// under the spinner. See `ModalSpinner::update_with_content`.
+    pub fn update_over_window_with_content(
+        &mut self,
+        ctx: &egui::Context,
+        window: &egui::Response,
+        content: impl FnOnce(&mut egui::Ui),
+    ) -> Option<SpinnerEvent> {
+        let region = Region {
+            rect: window.rect,
+            parent_layer: Some(window.layer_id),
+            rounding: ctx.style().visuals.window_rounding,
+            covers_screen: false,
+        };
+
+        self.update_ui(ctx, region, content)
+    }
+}
+
+/// Helper methods
+impl ModalSpinner {
+    /// Gets the time until the show delay has elapsed.
+    fn remaining_delay(&self) -> Duration {
+        self.show_delay
+            .saturating_sub(self.timestamp.elapsed().unwrap_or_default())
+    }
+
+    /// If the show delay has elapsed since opening the spinner.
+    fn is_delay_elapsed(&self) -> bool {
+        self.remaining_delay().is_zero()
+    }
+
+    /// Gets the time the modal must remain visible until the minimum display time has elapsed.
+    /// Returns zero if the modal has not yet appeared.
+    fn remaining_display_time(&self) -> Duration {
+        if !self.is_delay_elapsed() {
+            return Duration::ZERO;
+        }
+
+        self.min_display_time.saturating_sub(self.visible_time())
+    }
+
+    /// Gets the time the modal is visible since the show delay has elapsed.
+    fn visible_time(&self) -> Duration {
+        self.timestamp
+            .elapsed()
+            .unwrap_or_default()
+            .saturating_sub(self.show_delay)
+    }
+
+    /// Gets the progress of the open and close transitions in the range `0.0..=1.0`.
+    fn transition_progress(
+        &mut self,
+        ctx: &egui::Context,
+        id: egui::Id,
+        reduced_motion: bool,
+    ) -> f32 {
+        let fade_time = |duration: Option<Duration>| {
+            if reduced_motion {
+                return 0.0;
+            }
+
+            duration.map_or_else(|| ctx.style().animation_time, |d| d.as_secs_f32())
+        };
+
+        // The value is kept at one while the spinner is open, so that the
+        // fade out can be scaled by the progress of the fade in
+        let fade_out_time = if self.is_open() {
+            0.0
+        } else {
+            fade_time(self.fade_out_duration)
+        };
+
+        let fade_out = ctx.animate_bool_with_time_and_easing(
+            id.with("fade_out"),
+            self.is_open(),
+            fade_out_time,
+            self.easing,
+        );
+
+        // The fade out starts at the progress the fade in reached before closing
+        if self.fading_out {
+            return fade_out * self.fade_in_progress;
+        }
+
+        let fade_in_time = if self.fade_in {
+            fade_time(self.fade_in_duration)
+        } else {
+            0.0
+        };
+
+        self.fade_in_progress = if fade_in_time > 0.0 {
+            let t = self.visible_time().as_secs_f32() / fade_in_time;
+
+            if t < 1.0 {
+                ctx.request_repaint();
+            }
+
+            (self.easing)(t.min(1.0))
+        } else {
+            1.0
+        };
+
+        self.fade_in_progress
+    }
+
+    /// Checks if animations should be reduced.
+    fn is_reduced_motion(&self, ctx: &egui::Context) -> bool {
+        self.reduced_motion || (self.reduced_motion_from_style && ctx.style().animation_time <= 0.0)
+    }
+
+    /// Gets the region covering the whole screen.
+    fn screen_region(ctx: &egui::Context) -> Region {
+        Region {
+            rect: ctx.input(|i| i.screen_rect),
+            parent_layer: None,
+            rounding: egui::Rounding::ZERO,
+            covers_screen: true,
+        }
+    }
+
+    /// Creates an area of the modal at the given position inside the given region.
+    fn modal_area(&self, id: egui::Id, region: &Region, pos: egui::Pos2) -> egui::Area {
+        // The modal is faded in using its own animation
+        // The modal itself is not focusable, so that tab moves the focus directly
+        // to the widgets inside the modal
+        let mut area = egui::Area::new(id)
+            .movable(false)
+            .interactable(self.blocking)
+            .sense(NON_FOCUSABLE_CLICK)
+            .fade_in(false)
+            .fixed_pos(pos);
+
+        if let Some(parent) = region.parent_layer {
+            area = area.order(parent.order);
+        }
+
+        area
+    }
+
+    /// Moves the layers of the modal above the layer they should cover.
+    /// The content layer is always kept above the background layers.
+    fn order_layers(
+        ctx: &egui::Context,
+        background: &[egui::LayerId],
+        content: Option<egui::LayerId>,
+        region: &Region,
+    ) {
+        // Background layers created while the content is already visible are placed above
+        // the content. In this case, only the content is moved to the top to restore the order.
+        let content_below = content.is_some_and(|content| {
+            ctx.memory(|m| {
+                let order: Vec<egui::LayerId> = m.layer_ids().collect();
+                let position = |layer| order.iter().position(|l| *l == layer);
+
+                background
+                    .iter()
+                    .any(|layer| position(*layer) > position(content))
+            })
+        });
+
+        for layer_id in background.iter().copied().chain(content) {
+            match region.parent_layer {
+                Some(parent) => ctx.set_sublayer(parent, layer_id),
+                None if content_below && Some(layer_id) != content => (),
+                None => ctx.move_to_top(layer_id),
+            }
+        }
+
+        // Sublayers are ordered by their position in the layer order
+        if let (Some(content), Some(_)) = (content, region.parent_layer) {
+            ctx.move_to_top(content);
+        }
+    }
+
+    /// Gets the key that requests the cancellation, if the spinner is cancellable.
+    fn active_cancel_key(&self) -> Option<egui::Key> {
+        self.cancel_key
+            .filter(|_| self.cancellable && self.blocking)
+    }
+
+    /// Requests the cancellation of the task if the cancel key was pressed.
+    /// Pressing the key while the user is asked to confirm the cancellation aborts it.
+    fn update_cancel_key(&mut self, ctx: &egui::Context, id: egui::Id) -> Option<SpinnerEvent> {
+        // The key is captured by the input suppression at the beginning of the pass.
+        // Otherwise, the key is read directly.
+        let captured = input::take_captured_key(ctx, id);
+
+        let key = self.active_cancel_key()?;
+
+        if !self.is_open() {
+            return None;
+        }
+
+        let pressed = captured || ctx.input_mut(|i| i.consume_key(egui::Modifiers::NONE, key));
+
+        if !pressed {
+            return None;
+        }
+
+        if self.state == SpinnerState::ConfirmingCancel {
+            self.state = SpinnerState::Open;
+            return None;
+        }
+
+        self.request_cancel()
+    }
+
+    /// Requests the cancellation of the task on behalf of the user.
+    /// If enabled, the user must confirm the cancellation first.
+    fn request_cancel(&mut self) -> Option<SpinnerEvent> {
+        match self.state {
+            SpinnerState::Open if self.confirm_cancel => {
+                self.state = SpinnerState::ConfirmingCancel;
+                None
+            }
+            SpinnerState::Open | SpinnerState::ConfirmingCancel => {
+                self.cancel();
+                Some(SpinnerEvent::CancelRequested)
+            }
+            _ => None,
+        }
+    }
+
+    /// Announces the opening of the spinner, the stage changes and the closing of the
+    /// spinner to assistive technology.
+    fn update_announcements(&mut self, ctx: &egui::Context) {
+        let visible = self.is_open() && self.is_delay_elapsed();
+        let stage = self.current_stage();
+        let stage_name = stage.and_then(|index| self.stages.get(index));
+
+        let announcement = if visible && !self.announced {
+            Some(stage_name.map_or_else(
+                || self.busy_text.clone(),
+                |name| format!("{}: {name}", self.busy_text),
+            ))
+        } else if visible && self.announced_stage != stage {
+            stage_name.cloned()
+        } else if self.announced && !self.is_open() {
+            Some(self.finished_text.clone())
+        } else {
+            None
+        };
+
+        if self.is_open() {
+            self.announced |= visible;
+            self.announced_stage = stage;
+        } else {
+            self.announced = false;
+        }
+
+        if let Some(text) = announcement {
+            Self::announce(ctx, &text);
+
+            #[cfg(feature = "accesskit")]
+            {
+                self.announcement = Some((text, SystemTime::now()));
+            }
+        }
+    }
+
+    /// Announces the given text to screen readers using the output events of egui.
+    fn announce(ctx: &egui::Context, text: &str) {
+        let info = egui::WidgetInfo::labeled(egui::WidgetType::ProgressIndicator, true, text);
+        ctx.output_mut(|o| o.events.push(egui::output::OutputEvent::ValueChanged(info)));
+    }
+
+    /// Keeps the last announcement in a live region for a few seconds, so that it is
+    /// read by screen readers using AccessKit.
+    #[cfg(feature = "accesskit")]
+    fn update_announcement_node(&mut self, ctx: &egui::Context, id: egui::Id) {
+        const ANNOUNCEMENT_DURATION: Duration = Duration::from_secs(5);
+
+        let Some((text, timestamp)) = &self.announcement else {
+            return;
+        };
+
+        let remaining =
+            ANNOUNCEMENT_DURATION.saturating_sub(timestamp.elapsed().unwrap_or_default());
+
+        if remaining.is_zero() {
+            self.announcement = None;
+            return;
+        }
+
+        ctx.accesskit_node_builder(id.with("announcement"), |node| {
+            node.set_role(egui::accesskit::Role::Status);
+            node.set_live(egui::accesskit::Live::Polite);
+            node.set_label(text.clone());
+        });
+
+        ctx.request_repaint_after(remaining);
+    }
+
+    /// Gets the label of the spinner used by assistive technology.
+    fn accessibility_label(&self) -> String {
+        match self.state {
+            SpinnerState::Cancelling => return self.cancelling_text.clone(),
+            SpinnerState::ConfirmingCancel => return self.confirm_cancel_text.clone(),
+            _ => (),
+        }
+
+        if let Some(message) = &self.status_message {
+            return message.clone();
+        }
+
+        self.current_stage()
+            .and_then(|index| self.stages.get(index))
+            .unwrap_or(&self.busy_text)
+            .clone()
+    }
+
+    /// Applies all updates sent by the progress handles.
+    /// Returns true if at least one update was applied.
+    fn drain_progress_updates(&mut self) -> bool {
+        let updates: Vec<ProgressUpdate> = self
+            .shared
+            .progress_rx
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .try_iter()
+            .collect();
+
+        let changed = !updates.is_empty();
+
+        for update in updates {
+            match update {
+                ProgressUpdate::Message(message) => self.status_message = message,
+                ProgressUpdate::Progress(Some(progress)) => self.set_progress(progress),
+                ProgressUpdate::Progress(None) => self.clear_progress(),
+                ProgressUpdate::Stage(index) => {
+                    stages::lock(&self.shared.stage_progress).set_stage(index);
+                }
+                ProgressUpdate::AdvanceStage => stages::lock(&self.shared.stage_progress).advance(),
+            }
+        }
+
+        changed
+    }
+
+    /// Checks if the timeout has elapsed and applies the configured timeout behavior.
+    /// Returns `SpinnerEvent::TimedOut` in the frame the timeout elapsed.
+    fn update_timeout(&mut self, ctx: &egui::Context) -> Option<SpinnerEvent> {
+        let timeout = self.timeout?;
+
+        if self.timed_out
+            || !matches!(
+                self.state,
+                SpinnerState::Open | SpinnerState::ConfirmingCancel | SpinnerState::Cancelling
+            )
+        {
+            return None;
+        }
+
+        let remaining = timeout.saturating_sub(self.timestamp.elapsed().unwrap_or_default());
+
+        if !remaining.is_zero() {
+            ctx.request_repaint_after(remaining);
+            return None;
+        }
+
+        self.timed_out = true;
+
+        if self.timeout_behavior == TimeoutBehavior::Close {
+            self.close();
+        }
+
+        Some(SpinnerEvent::TimedOut)
+    }
+
+    /// Suppresses the keyboard input while the spinner is open and restores the
+    /// keyboard focus once the spinner is closed.
+    fn update_keyboard(&mut self, ctx: &egui::Context, id: egui::Id, region: &Region) {
+        if !self.is_open() || !self.blocking || !self.suppress_keyboard {
+            if let Some(previous) = self.previous_focus.take() {
+                if ctx.memory(|m| m.focused().is_none()) {
+                    ctx.memory_mut(|m| m.request_focus(previous));
+                }
+            }
+
+            return;
+        }
+
+        let focused = ctx
+            .memory(egui::Memory::focused)
+            .and_then(|focused| ctx.read_response(focused));
+
+        let passes_through = focused.as_ref().is_some_and(|r| {
+            self.pass_through_rects
+                .iter()
+                .any(|rect| rect.contains_rect(r.rect))
+        });
+
+        let layer = self.modal_area(id, region, egui::Pos2::ZERO).layer();
+
+        if region.covers_screen && !passes_through {
+            input::block_keyboard(
+                ctx,
+                id,
+                layer,
+                &self.allowed_shortcuts,
+                self.active_cancel_key(),
+            );
+        }
+
+        // Limits the focus cycling using tab to the widgets inside the modal.
+        // Not possible with pass-through rects, since the modal layer also blocks
+        // the pointer input of all layers below.
+        if region.covers_screen && self.pass_through_rects.is_empty() {
+            ctx.memory_mut(|m| m.set_modal_layer(layer));
+        }
+
+        let Some(focused) = focused else {
+            return;
+        };
+
+        // Widgets inside the modal itself, like the cancel button, keep the focus
+        // and receive the keyboard input
+        let behind_modal =
+            !passes_through && focused.layer_id.id != id && region.rect.intersects(focused.rect);
+
+        if behind_modal {
+            let focused = focused.id;
+
+            self.previous_focus.get_or_insert(focused);
+            ctx.memory_mut(|m| m.surrender_focus(focused));
+        }
+    }
+}
+
+/// UI methods
+impl ModalSpinner {
+    fn update_ui(
+        &mut self,
+        ctx: &egui::Context,
+        region: Region,
+        content: impl FnOnce(&mut egui::Ui),
+    ) -> Option<SpinnerEvent> {
+        if self.drain_progress_updates() {
+            ctx.request_repaint();
+        }
+
+        if self
+            .task_finished
+            .as_ref()
+            .is_some_and(|f| f.load(Ordering::Acquire))
+        {
+            self.close();
+        }
+
+        if self.state == SpinnerState::PendingClose {
+            let remaining = self.remaining_display_time();
+
+            if remaining.is_zero() {
+                self.close();
+            } else {
+                ctx.request_repaint_after(remaining);
+            }
+        }
+
+        let timeout_event = self.update_timeout(ctx);
+        self.pending_events.extend(timeout_event);
+
+        let id = self.id.unwrap_or_else(|| egui::Id::from("_modal_spinner"));
+        self.update_keyboard(ctx, id, &region);
+
+        let cancel_event = self.update_cancel_key(ctx, id);
+        self.pending_events.extend(cancel_event);
+
+        self.update_announcements(ctx);
+
+        #[cfg(feature = "accesskit")]
+        self.update_announcement_node(ctx, id);
+
+        if !self.is_open() && !self.fading_out {
+            return self.next_event(ctx);
+        }
+
+        let rect = region.rect;
+
+        if !self.is_delay_elapsed() {
+            if self.blocking {
+                let background = self.ui_update_background(ctx, id.with("delay"), &region, None);
+                Self::order_layers(ctx, &background, None, &region);
+            }
+
+            ctx.request_repaint_after(self.remaining_delay());
+            return self.next_event(ctx);
+        }
+
+        let t = self.transition_progress(ctx, id, self.is_reduced_motion(ctx));
+
+        if t <= 0.0 && self.fading_out {
+            self.fading_out = false;
+            return self.next_event(ctx);
+        }
+
+        let background = if self.blocking {
+            let fill_color = self.fill_color.unwrap_or_else(|| {
+                if ctx.style().visuals.dark_mode {
+                    egui::Color32::from_black_alpha(120)
+                } else {
+                    egui::Color32::from_white_alpha(40)
+                }
+            });
+
+            let opacity = self.transition.background_opacity(t);
+            self.ui_update_background(ctx, id, &region, Some((fill_color, opacity)))
+        } else {
+            Vec::new()
+        };
+
+        let pos = self.content_rect(&rect).unwrap_or(rect).left_top();
+
+        let re = self.modal_area(id, &region, pos).show(ctx, |ui| {
+            ui.multiply_opacity(self.transition.content_opacity(t));
+            self.ui_update_content(ui, &rect, content)
+        });
+
+        let transform = self.transition.content_transform(t, re.response.rect, rect);
+        ctx.set_transform_layer(re.response.layer_id, transform);
+
+        Self::order_layers(ctx, &background, Some(re.response.layer_id), &region);
+
+        self.pending_events.extend(re.inner);
+        self.next_event(ctx)
+    }
+
+    /// Takes the next pending event.
+    /// If more events are pending, a repaint is requested to return them in the next frame.
+    fn next_event(&mut self, ctx: &egui::Context) -> Option<SpinnerEvent> {
+        let event = self.pending_events.pop_front();
+
+        if !self.pending_events.is_empty() {
+            ctx.request_repaint();
+        }
+
+        event
+    }
+
+    /// Suppresses user input inside the region, except for the pass-through rects,
+    /// and optionally fills the background with the given color and opacity.
+    ///
+    /// Every part of the background is placed in its own area, so that no area
+    /// covers the pass-through rects. Returns the layers of the areas.
+    fn ui_update_background(
+        &self,
+        ctx: &egui::Context,
+        id: egui::Id,
+        region: &Region,
+        fill: Option<(egui::Color32, f32)>,
+    ) -> Vec<egui::LayerId> {
+        let parts = input::subtract_rects(region.rect, &self.pass_through_rects);
+
+        let rounding = if parts == [region.rect] {
+            region.rounding
+        } else {
+            egui::Rounding::ZERO
+        };
+
+        parts
+            .iter()
+            .enumerate()
+            .map(|(index, part)| {
+                self.modal_area(id.with(("background", index)), region, part.left_top())
+                    .show(ctx, |ui| {
+                        if let Some((fill_color, opacity)) = fill {
+                            ui.multiply_opacity(opacity);
+                            ui.painter().rect_filled(*part, rounding, fill_color);
+                        }
+
+                        ui.allocate_response(part.size(), NON_FOCUSABLE_CLICK);
+                    })
+                    .response
+                    .layer_id
+            })
+            .collect()
+    }
+
+    /// Updates the spinner together with the additional content and places them
+    /// inside the given rect according to the configured anchor.
+    ///
+    /// The size of the content is measured in every frame and used to place the content
+    /// in the next frame. If the size is not yet known, an invisible sizing pass is
+    /// performed and the current frame is discarded.
+    fn ui_update_content(
+        &mut self,
+        ui: &mut egui::Ui,
+        rect: &egui::Rect,
+        content: impl FnOnce(&mut egui::Ui),
+    ) -> Option<SpinnerEvent> {
+        let layout = egui::Layout::top_down(egui::Align::Center);
+
+        let Some(size) = self.content_size else {
+            let child_ui = egui::UiBuilder::new()
+                .max_rect(*rect)
+                .layout(layout)
+                .sizing_pass()
+                .invisible();
+
+            let re = ui.allocate_new_ui(child_ui, |ui| {
+                self.ui_update_block(ui, content);
+            });
+
+            self.content_size = Some(re.response.rect.size());
+            ui.ctx().request_discard("modal spinner sizing pass");
+
+            return None;
+        };
+
+        let overflowing = size.y > rect.height();
+        let content_rect = self.content_rect(rect).unwrap_or(*rect);
+
+        let child_ui = egui::UiBuilder::new()
+            .max_rect(content_rect)
+            .layout(egui::Layout::top_down(self.anchor.x()));
+
+        let re = ui.allocate_new_ui(child_ui, |ui| {
+            if !overflowing {
+                let event = self.ui_update_block(ui, content);
+                return (event, ui.min_rect().size());
+            }
+
+            let output = egui::ScrollArea::vertical()
+                .max_height(rect.height())
+                .show(ui, |ui| self.ui_update_block(ui, content));
+
+            (output.inner, output.content_size)
+        });
+
+        self.ui_update_accessibility(&re.response);
+
+        let (event, measured) = re.inner;
+
+        if measured != size {
+            self.content_size = Some(measured);
+            ui.ctx().request_repaint();
+        }
+
+        event
+    }
+
+    /// Exposes the spinner to assistive technology as a busy progress indicator
+    /// labeled with the current status and progress.
+    fn ui_update_accessibility(&self, response: &egui::Response) {
+        response.widget_info(|| egui::WidgetInfo {
+            value: self.progress.map(f64::from),
+            ..egui::WidgetInfo::labeled(
+                egui::WidgetType::ProgressIndicator,
+                true,
+                self.accessibility_label(),
+            )
+        });
+
+        #[cfg(feature = "accesskit")]
+        response.ctx.accesskit_node_builder(response.id, |node| {
+            node.set_busy();
+
+            if self.progress.is_some() {
+                node.set_min_numeric_value(0.0);
+                node.set_max_numeric_value(1.0);
+            }
+        });
+    }
+
+    /// Gets the rect in which the spinner and the additional content are placed inside
+    /// the given rect. Returns None if the size of the content is not yet known.
+    fn content_rect(&self, rect: &egui::Rect) -> Option<egui::Rect> {
+        let size = self.content_size?;
+
+        let block = self
+            .anchor
+            .align_size_within_rect(size, *rect)
+            .translate(self.anchor_offset);
+
+        // Keep the content inside the rect. Not using clamp here, since the content
+        // might be larger than the rect.
+        let block = egui::Rect::from_min_size(
+            egui::pos2(
+                block.left().min(rect.right() - size.x).max(rect.left()),
+                block.top().min(rect.bottom() - size.y).max(rect.top()),
+            ),
+            size,
+        );
+
+        // The content is aligned horizontally using the layout, so that the exact
+        // measured width is not required.
+        let (left, right) = match self.anchor.x() {
+            egui::Align::Min => (block.left(), rect.right()),
+            egui::Align::Center => {
+                let center = block.center().x;
+                let half_width = (center - rect.left()).min(rect.right() - center);
+                (center - half_width, center + half_width)
+            }
+            egui::Align::Max => (rect.left(), block.right()),
+        };
+
+        let top = if size.y > rect.height() {
+            rect.top()
+        } else {
+            block.top()
+        };
+
+        Some(egui::Rect::from_min_max(
+            egui::pos2(left, top),
+            egui::pos2(right, rect.bottom()),
+        ))
+    }
+
+    /// Updates the spinner together with the additional content.
+    /// If enabled, the content is displayed inside a card.
+    fn ui_update_block(
+        &mut self,
+        ui: &mut egui::Ui,
+        content: impl FnOnce(&mut egui::Ui),
+    ) -> Option<SpinnerEvent> {
+        if !self.show_card {
+            let event = self.ui_update_spinner(ui);
+            content(ui);
+            return event;
+        }
+
+        let frame = self
+            .card_frame
+            .unwrap_or_else(|| egui::Frame::window(ui.style()));
+
+        let available = ui.available_rect_before_wrap();
+        let width = self
+            .card_max_width
+            .unwrap_or(f32::INFINITY)
+            .min(available.width());
+
+        let card_rect = egui::Align2([ui.layout().horizontal_align(), egui::Align::Min])
+            .align_size_within_rect(egui::vec2(width, available.height()), available);
+
+        let child_ui = egui::UiBuilder::new()
+            .max_rect(card_rect)
+            .layout(egui::Layout::top_down(egui::Align::Center));
+
+        ui.allocate_new_ui(child_ui, |ui| {
+            frame
+                .show(ui, |ui| {
+                    let event = self.ui_update_spinner(ui);
+                    content(ui);
+                    event
+                })
+                .inner
+        })
+        .inner
+    }
+
+    fn ui_update_spinner(&mut self, ui: &mut egui::Ui) -> Option<SpinnerEvent> {
+        let show_timeout_message =
+            self.timed_out && self.timeout_behavior == TimeoutBehavior::ShowMessage;
+        let show_spinner = self.progress.is_none() || !self.hide_spinner_on_progress;
+
+        let reduced_motion = self.is_reduced_motion(ui.ctx());
+
+        if show_spinner {
+            self.spinner.update(ui, reduced_motion);
+        }
+
+        // The busy state is communicated using text instead of the animation
+        if reduced_motion && self.status_message.is_none() {
+            ui.add_space(ui.spacing().item_spacing.y);
+            ui.label(&self.busy_text);
+        }
+
+        if let Some(progress) = self.progress {
+            self.ui_update_progress_bar(ui, progress);
+        }
+
+        if let Some(message) = &self.status_message {
+            ui.add_space(ui.spacing().item_spacing.y);
+            ui.label(message);
+        }
+
+        if self.show_elapsed_time {
+            self.ui_update_elapsed_time(ui);
+        }
+
+        if !self.stages.is_empty() {
+            self.ui_update_stages(ui);
+        }
+
+        // The buttons cannot be clicked if the spinner is not blocking
+        let cancel_event = if self.cancellable && self.blocking {
+            self.ui_update_cancel_button(ui)
+        } else {
+            None
+        };
+
+        if show_timeout_message && self.ui_update_timeout_message(ui) {
+            self.pending_events.extend(cancel_event);
+            self.close();
+            return Some(SpinnerEvent::Dismissed);
+        }
+
+        cancel_event
+    }
+
+    /// Updates the message displayed after the timeout has elapsed.
+    /// Returns true if the user clicked the dismiss button.
+    fn ui_update_timeout_message(&self, ui: &mut egui::Ui) -> bool {
+        ui.add_space(ui.spacing().item_spacing.y);
+        ui.label(&self.timeout_text);
+
+        self.blocking && ui.button(&self.dismiss_button_text).clicked()
+    }
+
+    fn ui_update_stages(&self, ui: &mut egui::Ui) {
+        ui.add_space(ui.spacing().item_spacing.y);
+
+        // The elapsed time of the active stage is displayed with one decimal place
+        if self.current_stage().is_some() {
+            ui.ctx().request_repaint_after(Duration::from_millis(100));
+        }
+
+        let progress = stages::lock(&self.shared.stage_progress);
+
+        egui::Grid::new(ui.id().with("stages")).show(ui, |ui| {
+            for (index, name) in self.stages.iter().enumerate() {
+                let state = progress.state(index);
+
+                let marker = match state {
+                    StageState::Done => "✔",
+                    StageState::Active => "▶",
+                    StageState::Pending => "○",
+                };
+
+                let text = egui::RichText::new(name);
+                let text = match state {
+                    StageState::Done => text,
+                    StageState::Active => text.strong(),
+                    StageState::Pending => text.weak(),
+                };
+
+                ui.label(marker);
+                ui.label(text);
+
+                if let Some(elapsed) = progress.elapsed(index) {
+                    ui.label(format!("{:.1} s", elapsed.as_secs_f32()));
+                }
+
+                ui.end_row();
+            }
+        });
+    }
+
+    fn ui_update_cancel_button(&mut self, ui: &mut egui::Ui) -> Option<SpinnerEvent> {
+        ui.add_space(ui.spacing().item_spacing.y);
+
+        if self.state == SpinnerState::ConfirmingCancel {
+            return self.ui_update_confirm_cancel(ui);
+        }
+
+        if self.state == SpinnerState::Cancelling {
+            ui.add_enabled(false, egui::Button::new(&self.cancelling_text));
+            return None;
+        }
+
+        if self.state == SpinnerState::PendingClose {
+            ui.add_enabled(false, egui::Button::new(&self.cancel_button_text));
+            return None;
+        }
+
+        if ui.button(&self.cancel_button_text).clicked() {
+            return self.request_cancel();
+        }
+
+        None
+    }
+
+    /// Updates the question asking the user to confirm the cancellation.
+    fn ui_update_confirm_cancel(&mut self, ui: &mut egui::Ui) -> Option<SpinnerEvent> {
+        ui.label(&self.confirm_cancel_text);
+
+        let (yes, no) = egui::Grid::new(ui.id().with("confirm_cancel"))
+            .show(ui, |ui| {
+                let yes = ui.button(&self.confirm_yes_text).clicked();
+                let no = ui.button(&self.confirm_no_text).clicked();
+                (yes, no)
+            })
+            .inner;
+
+        if no {
+            self.state = SpinnerState::Open;
+        }
+
+        if yes {
+            return self.request_cancel();
+        }
+
+        None
+    }
+
+    fn ui_update_progress_bar(&self, ui: &mut egui::Ui, progress: f32) {
+        ui.add_space(ui.spacing().item_spacing.y);
+
+        let mut progress_bar =
+            egui::ProgressBar::new(progress).desired_width(self.progress_bar_width);
+
+        if self.show_progress_text {
+            progress_bar = progress_bar.show_percentage();
+        }
+
+        ui.add(progress_bar);
+    }
+
+    fn ui_update_elapsed_time(&self, ui: &mut egui::Ui) {
+        let elapsed = self.timestamp.elapsed().unwrap_or_default();
+
+        ui.add_space(ui.spacing().item_spacing.y);
+        ui.label(format!("Elapsed: {} s", elapsed.as_secs()));
+
+        // The indicator might not request a repaint every frame
+        let next_second = Duration::from_secs(elapsed.as_secs() + 1);
+        ui.ctx()
+            .request_repaint_after(next_second.saturating_sub(elapsed));
+    }
+}
+
+/// This tests if the spinner is send and sync.
+#[cfg(test)]
+const fn test_prop<T: Send + Sync>() {}
+
+#[test]
+const fn test() {
+    test_prop::<ModalSpinner>();
+    test_prop::<TaskError>();
+    test_prop::<CancellationToken>();
+    test_prop::<SpinnerEvent>();
+    test_prop::<StageHandle>();
+    test_prop::<ProgressHandle>();
+}
+
+#[test]
+fn test_progress_handle() {
+    let ctx = egui::Context::default();
+    let mut spinner = ModalSpinner::new().stages(["A", "B"]);
+    spinner.open();
+
+    let handle = spinner.progress_handle(&ctx);
+    handle.set_message("Loading");
+    handle.set_progress(2.0);
+    handle.set_progress(f32::NAN);
+    handle.advance_stage();
+    assert!(ctx.has_requested_repaint());
+
+    assert!(spinner.drain_progress_updates());
+    assert_eq!(spinner.status_message(), Some("Loading"));
+    assert_eq!(spinner.progress(), Some(1.0));
+    assert_eq!(spinner.current_stage(), Some(1));
+
+    spinner.set_progress(f32::INFINITY);
+    assert_eq!(spinner.progress(), Some(1.0));
+    assert!(!spinner.drain_progress_updates());
+
+    spinner.close();
+    spinner.open();
+    assert_eq!(spinner.status_message(), None);
+    assert_eq!(spinner.progress(), None);
+}
+
+#[test]
+fn test_cancel() {
+    let mut spinner = ModalSpinner::new().cancellable(true);
+    let token = spinner.cancellation_token();
+
+    spinner.cancel();
+    assert!(!token.is_cancelled());
+
+    spinner.open();
+    spinner.cancel();
+    assert_eq!(spinner.state(), &SpinnerState::Cancelling);
+    assert!(token.is_cancelled());
+
+    spinner.close();
+    assert!(!spinner.cancellation_token().is_cancelled());
+}
+
+#[test]
+fn test_cancel_key() {
+    let ctx = egui::Context::default();
+    let mut spinner = ModalSpinner::new().cancellable(true).confirm_cancel(true);
+    let token = spinner.cancellation_token();
+
+    let escape = || {
+        vec![egui::Event::Key {
+            key: egui::Key::Escape,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers: egui::Modifiers::NONE,
+        }]
+    };
+
+    // Returns the events of all passes of the frame
+    let update = |spinner: &mut ModalSpinner, events: Vec<egui::Event>| {
+        let input = egui::RawInput {
+            events,
+            ..Default::default()
+        };
+
+        let mut spinner_events = Vec::new();
+        let _ = ctx.run(input, |ctx| spinner_events.extend(spinner.update(ctx)));
+        spinner_events
+    };
+
+    spinner.open();
+    assert_eq!(update(&mut spinner, escape()), []);
+    assert_eq!(spinner.state(), &SpinnerState::ConfirmingCancel);
+
+    // Pressing the key again aborts the cancellation
+    assert_eq!(update(&mut spinner, escape()), []);
+    assert_eq!(spinner.state(), &SpinnerState::Open);
+    assert!(!token.is_cancelled());
+
+    update(&mut spinner, escape());
+    assert_eq!(spinner.state(), &SpinnerState::ConfirmingCancel);
+
+    spinner.cancel();
+    assert_eq!(spinner.state(), &SpinnerState::Cancelling);
+    assert!(token.is_cancelled());
+
+    // The key press is not lost if the spinner times out in the same frame
+    let mut spinner = ModalSpinner::new()
+        .cancellable(true)
+        .timeout(Duration::ZERO)
+        .timeout_behavior(TimeoutBehavior::Event);
+
+    spinner.open();
+    let mut events = update(&mut spinner, escape());
+    events.extend(update(&mut spinner, Vec::new()));
+    assert_eq!(
+        events,
+        [SpinnerEvent::TimedOut, SpinnerEvent::CancelRequested]
+    );
+}
+
+#[test]
+fn test_announcements() {
+    let ctx = egui::Context::default();
+    let mut spinner = ModalSpinner::new().stages(["Loading dogs", "Loading cats"]);
+
+    let announcements = |spinner: &mut ModalSpinner| -> Vec<String> {
+        let output = ctx.run(egui::RawInput::default(), |ctx| {
+            spinner.update(ctx);
+        });
+
+        output
+            .platform_output
+            .events
+            .iter()
+            .filter_map(|event| event.widget_info().label.clone())
+            .collect()
+    };
+
+    spinner.open();
+    assert_eq!(announcements(&mut spinner), ["Busy: Loading dogs"]);
+    assert!(announcements(&mut spinner).is_empty());
+
+    spinner.stage_handle().advance();
+    assert_eq!(announcements(&mut spinner), ["Loading cats"]);
+
+    spinner.close();
+    assert_eq!(announcements(&mut spinner), ["Finished"]);
+}
+
+#[test]
+fn test_min_display_time() {
+    let mut spinner = ModalSpinner::new().min_display_time(Duration::from_secs(30));
+
+    spinner.open();
+    spinner.close();
+    assert_eq!(spinner.state(), &SpinnerState::PendingClose);
+
+    let mut spinner = ModalSpinner::new()
+        .show_delay(Duration::from_secs(30))
+        .min_display_time(Duration::from_secs(30));
+
+    spinner.open();
+    spinner.close();
+    assert_eq!(spinner.state(), &SpinnerState::Closed);
+}
+
+#[test]
+fn test_run() {
+    let ctx = egui::Context::default();
+    let mut spinner = ModalSpinner::new();
+
+    let mut task = spinner.run(&ctx, || 42);
+    assert!(spinner.is_open());
+
+    while spinner.is_open() {
+        let _ = ctx.run(egui::RawInput::default(), |ctx| {
+            spinner.update(ctx);
+        });
+        std::thread::yield_now();
+    }
+
+    assert_eq!(spinner.state(), &SpinnerState::Closed);
+    assert_eq!(task.take_result(), Some(Ok(42)));
+    assert_eq!(task.take_result(), None);
+}
+
+#[test]
+fn test_run_panic() {
+    let ctx = egui::Context::default();
+    let mut spinner = ModalSpinner::new();
+
+    let mut task = spinner.run(&ctx, || -> u32 { panic!("worker failed") });
+
+    while spinner.is_open() {
+        let _ = ctx.run(egui::RawInput::default(), |ctx| {
+            spinner.update(ctx);
+        });
+        std::thread::yield_now();
+    }
+
+    assert_eq!(spinner.state(), &SpinnerState::Closed);
+    assert_eq!(
+        task.take_result(),
+        Some(Err(TaskError::Panicked(String::from("worker failed"))))
+    );
+    assert_eq!(task.take_result(), None);
+}
+
+#[test]
+fn test_clone() {
+    let mut spinner = ModalSpinner::new().cancellable(true);
+    spinner.open();
+
+    let mut clone = spinner.clone();
+    clone
+        .progress_handle(&egui::Context::default())
+        .set_message("Loading");
+    clone.cancel();
+
+    assert!(!spinner.cancellation_token().is_cancelled());
+    assert!(!spinner.drain_progress_updates());
+    assert!(clone.drain_progress_updates());
+}
+
+#[test]
+fn test_content_rect() {
+    let rect = egui::Rect::from_min_size(egui::pos2(10.0, 20.0), egui::vec2(100.0, 100.0));
+
+    let content_rect = |anchor: egui::Align2, offset: egui::Vec2, size: egui::Vec2| {
+        let mut spinner = ModalSpinner::new().anchor(anchor, offset);
+        spinner.content_size = Some(size);
+        spinner.content_rect(&rect)
+    };
+
+    let size = egui::vec2(20.0, 10.0);
+    let rect_from = |left: f32, top: f32, right: f32| {
+        Some(egui::Rect::from_min_max(
+            egui::pos2(left, top),
+            egui::pos2(right, rect.bottom()),
+        ))
+    };
+
+    assert_eq!(ModalSpinner::new().content_rect(&rect), None);
+
+    // The content is centered in the rect
+    assert_eq!(
+        content_rect(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO, size),
+        rect_from(10.0, 65.0, 110.0)
+    );
+
+    assert_eq!(
+        content_rect(egui::Align2::LEFT_TOP, egui::Vec2::ZERO, size),
+        rect_from(10.0, 20.0, 110.0)
+    );
+    assert_eq!(
+        content_rect(egui::Align2::RIGHT_BOTTOM, egui::Vec2::ZERO, size),
+        rect_from(10.0, 110.0, 110.0)
+    );
+    assert_eq!(
+        content_rect(egui::Align2::CENTER_TOP, egui::vec2(30.0, 0.0), size),
+        rect_from(70.0, 20.0, 110.0)
+    );
+
+    // The offset cannot push the content out of the rect
+    assert_eq!(
+        content_rect(egui::Align2::LEFT_TOP, egui::vec2(-50.0, -50.0), size),
+        rect_from(10.0, 20.0, 110.0)
+    );
+    assert_eq!(
+        content_rect(egui::Align2::RIGHT_BOTTOM, egui::vec2(50.0, 50.0), size),
+        rect_from(10.0, 110.0, 110.0)
+    );
+
+    // Content larger than the rect starts at the top of the rect
+    assert_eq!(
+        content_rect(
+            egui::Align2::CENTER_BOTTOM,
+            egui::Vec2::ZERO,
+            egui::vec2(20.0, 150.0)
+        ),
+        rect_from(10.0, 20.0, 110.0)
+    );
+}
+
+#[test]
+fn test_non_blocking() {
+    let texts = |spinner: &mut ModalSpinner| -> Vec<String> {
+        let ctx = egui::Context::default();
+        let mut output = ctx.run(egui::RawInput::default(), |ctx| {
+            spinner.update(ctx);
+        });
+
+        // The first frames measure the content and fade the modal in
+        for _ in 0..3 {
+            output = ctx.run(egui::RawInput::default(), |ctx| {
+                spinner.update(ctx);
+            });
+        }
+
+        // Keyboard input is only suppressed by a blocking spinner
+        let input = egui::RawInput {
+            events: vec![egui::Event::Text(String::from("a"))],
+            ..Default::default()
+        };
+
+        let _ = ctx.run(input, |ctx| {
+            assert_eq!(ctx.input(|i| i.events.is_empty()), spinner.blocking);
+            spinner.update(ctx);
+        });
+
+        let background = egui::Id::new("_modal_spinner").with(("background", 0));
+        assert_eq!(
+            ctx.memory(|m| m.layer_ids().any(|l| l.id == background)),
+            spinner.blocking
+        );
+
+        output
+            .shapes
+            .iter()
+            .filter_map(|s| match &s.shape {
+                egui::Shape::Text(text) => Some(text.galley.text().to_owned()),
+                _ => None,
+            })
+            .collect()
+    };
+
+    let spinner = || {
+        let mut spinner = ModalSpinner::new()
+            .fade_in(false)
+            .show_elapsed_time(false)
+            .cancellable(true)
+            .timeout(Duration::ZERO);
+        spinner.open();
+        spinner
+    };
+
+    assert_eq!(
+        texts(&mut spinner()),
+        ["Cancel", "This is taking longer than expected", "Dismiss"]
+    );
+
+    // The buttons cannot be clicked without blocking and are not displayed
+    assert_eq!(
+        texts(&mut spinner().blocking(false)),
+        ["This is taking longer than expected"]
+    );
+}
+
+#[test]
+fn test_modal_focus() {
+    let ctx = egui::Context::default();
+    let mut spinner = ModalSpinner::new().cancellable(true).fade_in(false);
+
+    // Returns the focused widget and the button behind the modal
+    let update = |spinner: &mut ModalSpinner, key: Option<egui::Key>| {
+        let input = egui::RawInput {
+            events: key
+                .map(|key| egui::Event::Key {
+                    key,
+                    physical_key: None,
+                    pressed: true,
+                    repeat: false,
+                    modifiers: egui::Modifiers::NONE,
+                })
+                .into_iter()
+                .collect(),
+            ..Default::default()
+        };
+
+        let mut behind = None;
+        let _ = ctx.run(input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                behind = Some(ui.button("Behind").id);
+            });
+
+            spinner.update(ctx);
+        });
+
+        (ctx.memory(egui::Memory::focused), behind)
+    };
+
+    spinner.open();
+    update(&mut spinner, None);
+    update(&mut spinner, None);
+
+    // The focus cycles through the widgets inside the modal only
+    let (focused, behind) = update(&mut spinner, Some(egui::Key::Tab));
+    assert!(focused.is_some());
+    assert_ne!(focused, behind);
+
+    update(&mut spinner, Some(egui::Key::Enter));
+    assert_eq!(spinner.state(), &SpinnerState::Cancelling);
+}
+
+#[test]
+fn test_fade_out() {
+    let ctx = egui::Context::default();
+    let id = egui::Id::new("spinner");
+    let mut spinner = ModalSpinner::new()
+        .fade_in_duration(Duration::from_secs(10))
+        .fade_out_duration(Duration::from_secs(10));
+
+    spinner.open();
+    let _ = ctx.run(egui::RawInput::default(), |ctx| {
+        assert!(spinner.transition_progress(ctx, id, false) < 0.5);
+    });
+
+    // Closing during the fade in does not jump to full opacity
+    let fade_in = spinner.fade_in_progress;
+    spinner.close();
+
+    let _ = ctx.run(egui::RawInput::default(), |ctx| {
+        assert!(spinner.transition_progress(ctx, id, false) <= fade_in);
+    });
+
+    // Reopening during the fade out displays the modal immediately
+    spinner.open();
+
+    let _ = ctx.run(egui::RawInput::default(), |ctx| {
+        spinner.update(ctx);
+    });
+
+    let background = egui::Id::new("_modal_spinner").with(("background", 0));
+    assert!(ctx.memory(|m| m
+        .areas()
+        .visible_layer_ids()
+        .iter()
+        .any(|l| l.id == background)));
+}
+
+/// Wrapper above the configured `SpinnerIndicator` that allocates the space
+/// of the indicator and paints it.
+#[derive(Debug, Clone)]
+struct Spinner {
+    pub size: Option<f32>,
+    pub color: Option<egui::Color32>,
+    pub indicator: Arc<dyn SpinnerIndicator>,
+}
+
+impl Default for Spinner {
+    fn default() -> Self {
+        Self {
+            size: None,
+            color: None,
+            indicator: Arc::new(ArcIndicator::new()),
+        }
+    }
+}
+
+impl Spinner {
+    fn update(&self, ui: &mut egui::Ui, reduced_motion: bool) -> egui::Response {
+        let size = self
+            .size
+            .unwrap_or_else(|| ui.style().spacing.interact_size.y);
+
+        let (rect, response) = ui.allocate_exact_size(egui::vec2(size, size), egui::Sense::hover());
+        response.widget_info(|| egui::WidgetInfo::new(egui::WidgetType::ProgressIndicator));
+
+        if ui.is_rect_visible(rect) {
+            let color = self
+                .color
+                .unwrap_or_else(|| ui.visuals().strong_text_color());
+
+            // The indicator is responsible for applying the opacity itself
+            let mut painter = ui.painter().clone();
+            let opacity = painter.opacity();
+            painter.set_opacity(1.0);
+
+            let time = ui.input(|i| i.time);
+
+            let indicator: &dyn SpinnerIndicator = if reduced_motion {
+                &PulseIndicator::new()
+            } else {
+                self.indicator.as_ref()
+            };
+
+            indicator.paint(&painter, rect, color, time, opacity);
+
+            ui.ctx()
+                .request_repaint_after(indicator.repaint_after(time));
         }
 
-        spinner.ui(ui)
+        response
     }
 }
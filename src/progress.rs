@@ -0,0 +1,72 @@
+use std::sync::mpsc;
+
+/// Update sent from a `ProgressHandle` to the spinner.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ProgressUpdate {
+    /// Sets or clears the status message.
+    Message(Option<String>),
+    /// Sets or clears the progress fraction.
+    Progress(Option<f32>),
+    /// Sets the currently active stage.
+    Stage(usize),
+    /// Finishes the currently active stage and starts the next one.
+    AdvanceStage,
+}
+
+/// Thread-safe handle used to report the progress of a task to a `ModalSpinner`.
+///
+/// The handle can be obtained using `ModalSpinner::progress_handle` and cloned freely.
+/// The updates are applied by the spinner the next time `ModalSpinner::update` is called.
+/// Every update requests a repaint of the context, so that it is applied even if the UI is idle.
+#[derive(Debug, Clone)]
+pub struct ProgressHandle {
+    tx: mpsc::Sender<ProgressUpdate>,
+    ctx: egui::Context,
+}
+
+impl ProgressHandle {
+    pub(crate) const fn new(tx: mpsc::Sender<ProgressUpdate>, ctx: egui::Context) -> Self {
+        Self { tx, ctx }
+    }
+
+    /// Sets the status message displayed below the spinner.
+    pub fn set_message(&self, message: impl Into<String>) {
+        self.send(ProgressUpdate::Message(Some(message.into())));
+    }
+
+    /// Removes the status message.
+    pub fn clear_message(&self) {
+        self.send(ProgressUpdate::Message(None));
+    }
+
+    /// Sets the progress of the task and switches the spinner into determinate mode.
+    /// Non-finite values are ignored. See `ModalSpinner::set_progress`.
+    pub fn set_progress(&self, progress: f32) {
+        if progress.is_finite() {
+            self.send(ProgressUpdate::Progress(Some(progress)));
+        }
+    }
+
+    /// Removes the progress and switches the spinner back into indeterminate mode.
+    pub fn clear_progress(&self) {
+        self.send(ProgressUpdate::Progress(None));
+    }
+
+    /// Sets the currently active stage. All previous stages are marked as done.
+    /// See `ModalSpinner::stages`.
+    pub fn set_stage(&self, index: usize) {
+        self.send(ProgressUpdate::Stage(index));
+    }
+
+    /// Finishes the currently active stage and starts the next one.
+    pub fn advance_stage(&self) {
+        self.send(ProgressUpdate::AdvanceStage);
+    }
+
+    fn send(&self, update: ProgressUpdate) {
+        // The spinner might have already been dropped
+        if self.tx.send(update).is_ok() {
+            self.ctx.request_repaint();
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, SystemTime};
+
+/// Represents the state of a single stage of a multi-stage task.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StageState {
+    /// The stage has not yet been started.
+    Pending,
+    /// The stage is currently being executed.
+    Active,
+    /// The stage is finished.
+    Done,
+}
+
+/// Timestamps of a single stage.
+#[derive(Debug, Default, Clone, Copy)]
+struct StageTimes {
+    started: Option<SystemTime>,
+    finished: Option<SystemTime>,
+}
+
+/// Progress of the stages shared between the spinner and the stage handles.
+#[derive(Debug, Default, Clone)]
+pub struct StageProgress {
+    /// Index of the currently active stage.
+    /// Is equal to the number of stages once all stages are finished.
+    current: usize,
+    times: Vec<StageTimes>,
+}
+
+impl StageProgress {
+    /// Resets the progress and starts the first stage.
+    pub(crate) fn reset(&mut self, stage_count: usize) {
+        self.current = 0;
+        self.times = vec![StageTimes::default(); stage_count];
+
+        if let Some(first) = self.times.first_mut() {
+            first.started = Some(SystemTime::now());
+        }
+    }
+
+    /// Sets the currently active stage. All previous stages are marked as done.
+    pub(crate) fn set_stage(&mut self, index: usize) {
+        let index = index.min(self.times.len());
+        let now = SystemTime::now();
+
+        for times in self.times.iter_mut().take(index) {
+            times.started.get_or_insert(now);
+            times.finished.get_or_insert(now);
+        }
+
+        if let Some(times) = self.times.get_mut(index) {
+            times.started.get_or_insert(now);
+        }
+
+        self.current = index;
+    }
+
+    /// Finishes the currently active stage and starts the next one.
+    pub(crate) fn advance(&mut self) {
+        self.set_stage(self.current + 1);
+    }
+
+    /// Gets the index of the currently active stage.
+    /// Returns None if all stages are finished.
+    pub(crate) fn current(&self) -> Option<usize> {
+        (self.current < self.times.len()).then_some(self.current)
+    }
+
+    /// Gets the state of the stage with the given index.
+    pub(crate) fn state(&self, index: usize) -> StageState {
+        match index.cmp(&self.current) {
+            std::cmp::Ordering::Less => StageState::Done,
+            std::cmp::Ordering::Equal => StageState::Active,
+            std::cmp::Ordering::Greater => StageState::Pending,
+        }
+    }
+
+    /// Gets the time spent in the stage with the given index.
+    /// Returns None if the stage has not yet been started.
+    pub(crate) fn elapsed(&self, index: usize) -> Option<Duration> {
+        let times = self.times.get(index)?;
+        let started = times.started?;
+
+        let elapsed = times.finished.map_or_else(
+            || started.elapsed(),
+            |finished| finished.duration_since(started),
+        );
+
+        Some(elapsed.unwrap_or_default())
+    }
+}
+
+/// Thread-safe handle used to advance the stages of a `ModalSpinner` from a worker.
+///
+/// The handle can be obtained using `ModalSpinner::stage_handle` and cloned freely.
+#[derive(Debug, Clone)]
+pub struct StageHandle {
+    progress: Arc<Mutex<StageProgress>>,
+}
+
+impl StageHandle {
+    pub(crate) const fn new(progress: Arc<Mutex<StageProgress>>) -> Self {
+        Self { progress }
+    }
+
+    /// Finishes the currently active stage and starts the next one.
+    pub fn advance(&self) {
+        self.lock().advance();
+    }
+
+    /// Sets the currently active stage. All previous stages are marked as done.
+    pub fn set_stage(&self, index: usize) {
+        self.lock().set_stage(index);
+    }
+
+    /// Marks all stages as done.
+    pub fn finish(&self) {
+        let mut progress = self.lock();
+        let count = progress.times.len();
+        progress.set_stage(count);
+    }
+
+    /// Gets the index of the currently active stage.
+    /// Returns None if all stages are finished.
+    pub fn current(&self) -> Option<usize> {
+        self.lock().current()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, StageProgress> {
+        lock(&self.progress)
+    }
+}
+
+/// Locks the stage progress. A poisoned lock is ignored since the progress is
+/// only used for displaying purposes.
+pub fn lock(progress: &Mutex<StageProgress>) -> MutexGuard<'_, StageProgress> {
+    progress.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+#[test]
+fn test_stages() {
+    let progress = Arc::new(Mutex::new(StageProgress::default()));
+    lock(&progress).reset(3);
+
+    let handle = StageHandle::new(progress.clone());
+    assert_eq!(handle.current(), Some(0));
+
+    handle.advance();
+    assert_eq!(lock(&progress).state(0), StageState::Done);
+    assert_eq!(lock(&progress).state(1), StageState::Active);
+    assert_eq!(lock(&progress).state(2), StageState::Pending);
+
+    handle.finish();
+    assert_eq!(handle.current(), None);
+    assert!(lock(&progress).elapsed(2).is_some());
+}
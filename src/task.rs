@@ -0,0 +1,250 @@
+use std::any::Any;
+use std::fmt::Display;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+
+/// Error returned by a `SpinnerTask` if the task could not be completed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TaskError {
+    /// The worker panicked while executing the task.
+    /// Contains the panic message, if it could be extracted from the panic payload.
+    Panicked(String),
+    /// The worker ended without delivering a result.
+    Disconnected,
+}
+
+impl Display for TaskError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Panicked(msg) => write!(f, "task panicked: {msg}"),
+            Self::Disconnected => write!(f, "task ended without delivering a result"),
+        }
+    }
+}
+
+impl std::error::Error for TaskError {}
+
+/// Handle to a task started with `ModalSpinner::run`.
+///
+/// The spinner closes itself as soon as the task is finished.
+/// The result of the task can then be taken from the handle using `SpinnerTask::take_result`.
+#[derive(Debug)]
+pub struct SpinnerTask<T> {
+    /// Receiver of the result of the worker.
+    rx: mpsc::Receiver<Result<T, TaskError>>,
+    /// If the result was already taken from the handle.
+    taken: bool,
+}
+
+impl<T: Send + 'static> SpinnerTask<T> {
+    /// Spawns a new worker thread executing the given closure.
+    ///
+    /// Returns the task handle and a flag that is set once the worker finished.
+    pub(crate) fn spawn<F>(ctx: &egui::Context, task: F) -> (Self, Arc<AtomicBool>)
+    where
+        F: FnOnce() -> T + Send + 'static,
+    {
+        let (tx, rx) = mpsc::channel();
+        let finished = Arc::new(AtomicBool::new(false));
+
+        let sender = ResultSender::new(ctx, tx, finished.clone());
+
+        thread::spawn(move || {
+            let result = panic::catch_unwind(AssertUnwindSafe(task))
+                .map_err(|payload| TaskError::Panicked(panic_message(payload.as_ref())));
+
+            sender.send(result);
+        });
+
+        (Self { rx, taken: false }, finished)
+    }
+
+    /// Spawns the given future on the tokio runtime of the given handle.
+    ///
+    /// Returns the task handle and a flag that is set once the future resolved.
+    #[cfg(feature = "tokio")]
+    pub(crate) fn spawn_future<F>(
+        ctx: &egui::Context,
+        handle: &tokio::runtime::Handle,
+        future: F,
+    ) -> (Self, Arc<AtomicBool>)
+    where
+        F: std::future::Future<Output = T> + Send + 'static,
+    {
+        let (tx, rx) = mpsc::channel();
+        let finished = Arc::new(AtomicBool::new(false));
+
+        let sender = ResultSender::new(ctx, tx, finished.clone());
+
+        let join_handle = handle.spawn(future);
+
+        // The future is awaited by a second task so that a panic inside the future
+        // can be reported instead of leaving the spinner open. If the runtime shuts down
+        // before, the sender is dropped together with the task and still notifies the spinner.
+        handle.spawn(async move {
+            let result = join_handle.await.map_err(|err| {
+                if err.is_panic() {
+                    TaskError::Panicked(panic_message(err.into_panic().as_ref()))
+                } else {
+                    TaskError::Disconnected
+                }
+            });
+
+            sender.send(result);
+        });
+
+        (Self { rx, taken: false }, finished)
+    }
+}
+
+impl<T> SpinnerTask<T> {
+    /// Takes the result of the task.
+    ///
+    /// Returns `None` while the task is still running. Once the task is finished, the
+    /// result is returned exactly once. All subsequent calls return `None`.
+    pub fn take_result(&mut self) -> Option<Result<T, TaskError>> {
+        if self.taken {
+            return None;
+        }
+
+        let result = match self.rx.try_recv() {
+            Ok(result) => result,
+            Err(mpsc::TryRecvError::Empty) => return None,
+            Err(mpsc::TryRecvError::Disconnected) => Err(TaskError::Disconnected),
+        };
+
+        self.taken = true;
+        Some(result)
+    }
+
+    /// If the result of the task was already taken using `SpinnerTask::take_result`.
+    pub const fn is_taken(&self) -> bool {
+        self.taken
+    }
+}
+
+/// Sends the result of a worker to the task handle.
+///
+/// The spinner is notified once the sender is dropped, even if no result was sent.
+/// The task handle then reports `TaskError::Disconnected`.
+struct ResultSender<T> {
+    ctx: egui::Context,
+    tx: Option<mpsc::Sender<Result<T, TaskError>>>,
+    finished: Arc<AtomicBool>,
+}
+
+impl<T> ResultSender<T> {
+    fn new(
+        ctx: &egui::Context,
+        tx: mpsc::Sender<Result<T, TaskError>>,
+        finished: Arc<AtomicBool>,
+    ) -> Self {
+        Self {
+            ctx: ctx.clone(),
+            tx: Some(tx),
+            finished,
+        }
+    }
+
+    /// Sends the result and notifies the spinner.
+    fn send(mut self, result: Result<T, TaskError>) {
+        if let Some(tx) = self.tx.take() {
+            // The handle might have already been dropped by the user
+            let _ = tx.send(result);
+        }
+    }
+}
+
+impl<T> Drop for ResultSender<T> {
+    fn drop(&mut self) {
+        // The channel is closed before the flag is set, so that the result is
+        // available once the spinner sees the flag
+        self.tx = None;
+
+        self.finished.store(true, Ordering::Release);
+        self.ctx.request_repaint();
+    }
+}
+
+/// Extracts the message of a panic payload.
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(msg) = payload.downcast_ref::<&str>() {
+        return (*msg).to_string();
+    }
+
+    if let Some(msg) = payload.downcast_ref::<String>() {
+        return msg.clone();
+    }
+
+    String::from("unknown panic payload")
+}
+
+#[test]
+fn test_task_result() {
+    let ctx = egui::Context::default();
+
+    let (mut task, finished) = SpinnerTask::spawn(&ctx, || 42);
+
+    while !finished.load(Ordering::Acquire) {
+        thread::yield_now();
+    }
+
+    assert_eq!(task.take_result(), Some(Ok(42)));
+    assert_eq!(task.take_result(), None);
+    assert!(task.is_taken());
+}
+
+#[test]
+fn test_task_panic() {
+    let ctx = egui::Context::default();
+
+    let (mut task, finished) = SpinnerTask::<()>::spawn(&ctx, || panic!("worker failed"));
+
+    while !finished.load(Ordering::Acquire) {
+        thread::yield_now();
+    }
+
+    assert_eq!(
+        task.take_result(),
+        Some(Err(TaskError::Panicked(String::from("worker failed"))))
+    );
+}
+
+#[cfg(feature = "tokio")]
+#[test]
+fn test_future_result() -> std::io::Result<()> {
+    let ctx = egui::Context::default();
+    let runtime = tokio::runtime::Builder::new_current_thread().build()?;
+
+    let (mut task, finished) = SpinnerTask::spawn_future(&ctx, runtime.handle(), async { 42 });
+
+    runtime.block_on(async {
+        while !finished.load(Ordering::Acquire) {
+            tokio::task::yield_now().await;
+        }
+    });
+
+    assert_eq!(task.take_result(), Some(Ok(42)));
+
+    Ok(())
+}
+
+#[cfg(feature = "tokio")]
+#[test]
+fn test_future_runtime_shutdown() -> std::io::Result<()> {
+    let ctx = egui::Context::default();
+    let runtime = tokio::runtime::Builder::new_current_thread().build()?;
+
+    let (mut task, finished) =
+        SpinnerTask::<()>::spawn_future(&ctx, runtime.handle(), std::future::pending());
+
+    assert!(!finished.load(Ordering::Acquire));
+    drop(runtime);
+
+    assert!(finished.load(Ordering::Acquire));
+    assert_eq!(task.take_result(), Some(Err(TaskError::Disconnected)));
+
+    Ok(())
+}
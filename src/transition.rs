@@ -0,0 +1,104 @@
+use egui::emath::TSTransform;
+
+/// Represents the transition used when the spinner is opened or closed.
+///
+/// The transition is applied to both the modal background and the spinner content.
+/// It is animated using the configured fade durations and easing function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transition {
+    /// The background and the content fade in and out together.
+    Fade,
+    /// The background fades in while the content is scaled up from a smaller size.
+    Scale,
+    /// The background fades in while the content slides in from an edge of the covered region.
+    /// The content moves in the given direction, e.g. `egui::Direction::BottomUp` slides
+    /// the content in from the bottom edge.
+    Slide(egui::Direction),
+    /// The background fades in first and the content fades in once the background is
+    /// fully visible. The order is reversed when closing.
+    Backdrop,
+}
+
+impl Transition {
+    /// The scale of the content at the start of the `Transition::Scale` transition.
+    const INITIAL_SCALE: f32 = 0.8;
+
+    /// Gets the opacity of the background at the given progress of the transition.
+    pub(crate) fn background_opacity(self, t: f32) -> f32 {
+        match self {
+            Self::Backdrop => (t * 2.0).min(1.0),
+            _ => t,
+        }
+    }
+
+    /// Gets the opacity of the content at the given progress of the transition.
+    pub(crate) fn content_opacity(self, t: f32) -> f32 {
+        match self {
+            Self::Backdrop => t.mul_add(2.0, -1.0).max(0.0),
+            _ => t,
+        }
+    }
+
+    /// Gets the transform of the content at the given progress of the transition.
+    ///
+    /// * `content` - The rect of the content without any transform applied.
+    /// * `region` - The rect covered by the modal.
+    pub(crate) fn content_transform(
+        self,
+        t: f32,
+        content: egui::Rect,
+        region: egui::Rect,
+    ) -> TSTransform {
+        let remaining = 1.0 - t;
+
+        match self {
+            Self::Fade | Self::Backdrop => TSTransform::IDENTITY,
+            Self::Scale => {
+                let scale = egui::lerp(Self::INITIAL_SCALE..=1.0, t);
+                let center = content.center().to_vec2();
+
+                TSTransform::from_translation(center)
+                    * TSTransform::from_scaling(scale)
+                    * TSTransform::from_translation(-center)
+            }
+            Self::Slide(direction) => {
+                // The content starts just outside of the region
+                let offset = match direction {
+                    egui::Direction::LeftToRight => {
+                        egui::vec2(region.left() - content.right(), 0.0)
+                    }
+                    egui::Direction::RightToLeft => {
+                        egui::vec2(region.right() - content.left(), 0.0)
+                    }
+                    egui::Direction::TopDown => egui::vec2(0.0, region.top() - content.bottom()),
+                    egui::Direction::BottomUp => egui::vec2(0.0, region.bottom() - content.top()),
+                };
+
+                TSTransform::from_translation(offset * remaining)
+            }
+        }
+    }
+}
+
+#[test]
+fn test_transition() {
+    let region = egui::Rect::from_min_size(egui::Pos2::ZERO, egui::vec2(100.0, 100.0));
+    let content = egui::Rect::from_center_size(region.center(), egui::vec2(20.0, 20.0));
+
+    assert!((Transition::Backdrop.background_opacity(0.5) - 1.0).abs() < f32::EPSILON);
+    assert!(Transition::Backdrop.content_opacity(0.5).abs() < f32::EPSILON);
+
+    let slide = Transition::Slide(egui::Direction::BottomUp);
+    assert_eq!(
+        slide.content_transform(0.0, content, region) * content,
+        content.translate(egui::vec2(0.0, 60.0))
+    );
+    assert_eq!(
+        slide.content_transform(1.0, content, region),
+        TSTransform::IDENTITY
+    );
+
+    let scaled = Transition::Scale.content_transform(0.0, content, region) * content;
+    assert_eq!(scaled.center(), content.center());
+    assert!((scaled.width() - 16.0).abs() < 1e-4);
+}